@@ -0,0 +1,31 @@
+//! The parser behind `sonic_spin!`, for proc-macros that want to read the
+//! `::()` postfix operator in their own input.
+//!
+//! ```
+//! use quote::quote;
+//!
+//! let spun = sonic_spin_core::spin(quote!(let x = 1::(-);))?;
+//! assert_eq!(spun.to_string(), quote!({ let x = - 1; }).to_string());
+//! # Ok::<(), syn::Error>(())
+//! ```
+
+pub mod resyn;
+
+pub use resyn::expr::Block;
+
+use proc_macro2::{Delimiter, Group, TokenStream};
+use quote::ToTokens;
+
+/// Parses the statements of `tokens` as a block in which `::()` serves as a
+/// general postfix operator, and prints that block back in the classic
+/// syntax.
+///
+/// This is what `sonic_spin!` does, but on `proc_macro2` tokens and
+/// returning the parse error instead of emitting it. The tokens are braced
+/// as they are, so they keep their spans.
+pub fn spin(tokens: TokenStream) -> syn::Result<TokenStream> {
+    let rebraced = Group::new(Delimiter::Brace, tokens).into_token_stream();
+
+    let input: Block = syn::parse2(rebraced)?;
+    Ok(input.into_token_stream())
+}
@@ -14,6 +14,8 @@ use syn::{
     ast_enum, ast_enum_of_structs, ast_struct, generate_to_tokens, maybe_ast_struct, to_tokens_call,
 };
 
+#[cfg(all(feature = "parsing", feature = "printing"))]
+mod lower;
 pub mod turboball;
 
 ast_enum_of_structs! {
@@ -995,6 +997,34 @@ fn requires_terminator(expr: &Expr) -> bool {
     }
 }
 
+// Whether the expression binds as tightly as a method call, so that it can
+// be the receiver of a postfix turboball without parenthesis.
+#[cfg(feature = "full")]
+fn binds_as_trailer(expr: &Expr) -> bool {
+    match *expr {
+        Expr::Array(_)
+        | Expr::Call(_)
+        | Expr::MethodCall(_)
+        | Expr::Tuple(_)
+        | Expr::Lit(_)
+        | Expr::Field(_)
+        | Expr::Index(_)
+        | Expr::Path(_)
+        | Expr::Macro(_)
+        | Expr::Struct(_)
+        | Expr::Repeat(_)
+        | Expr::Paren(_)
+        | Expr::Try(_) => true,
+        // the compiler may flatten an invisible group, so it's not trusted
+        // to bind by itself, and a continue doesn't bind at all
+        Expr::Turboball(ref turboball) => match turboball.expr_mark {
+            turboball::ExprMark::Group(_) | turboball::ExprMark::Continue(_) => false,
+            ref mark => !mark.is_prefix() && !mark.is_infix(),
+        },
+        _ => false,
+    }
+}
+
 #[cfg(feature = "parsing")]
 pub mod parsing {
     use super::*;
@@ -1012,6 +1042,12 @@ pub mod parsing {
     #[derive(Copy, Clone)]
     pub struct AllowStruct(bool);
 
+    // Whether the operator is a compound assignment, such as `+=`.
+    #[cfg(feature = "full")]
+    pub fn is_assign_op(op: &syn::BinOp) -> bool {
+        Precedence::of(op) == Precedence::Assign
+    }
+
     #[derive(Copy, Clone, PartialEq, PartialOrd)]
     enum Precedence {
         Any,
@@ -1315,6 +1351,25 @@ pub mod parsing {
         }
     }
 
+    // Parses an expression that has no binary operators, so that a turboball
+    // sugar can tell its arguments apart, eg. `::(fold 0 |a, b| a + b)`.
+    #[cfg(feature = "full")]
+    pub fn expr_unary(input: ParseStream) -> Result<Expr> {
+        unary_expr(input, AllowStruct(true))
+    }
+
+    // Parses a turboball sugar argument that is followed by another one. Like
+    // the argument, the body of a closure has no binary operators, so that
+    // `::(map_or_else |e| 0 |x| x * 2)` reads as two closures.
+    #[cfg(feature = "full")]
+    pub fn expr_leading_arg(input: ParseStream) -> Result<Expr> {
+        if input.peek(syn::Token![|]) || input.peek(syn::Token![move]) {
+            closure(input, expr_unary).map(Expr::Closure)
+        } else {
+            expr_unary(input)
+        }
+    }
+
     #[cfg(not(feature = "full"))]
     fn unary_expr(input: ParseStream, allow_struct: AllowStruct) -> Result<Expr> {
         let ahead = input.fork();
@@ -1339,7 +1394,8 @@ pub mod parsing {
     #[cfg(feature = "full")]
     fn trailer_expr(input: ParseStream, allow_struct: AllowStruct) -> Result<Expr> {
         if input.peek(syn::token::Group) {
-            return input.call(expr_group).map(Expr::Group);
+            let group = input.call(expr_group).map(Expr::Group)?;
+            return trailer_helper(input, group);
         }
 
         let outer_attrs = input.call(syn::Attribute::parse_outer)?;
@@ -1367,28 +1423,11 @@ pub mod parsing {
             } else if input.peek(syn::Token![.]) && !input.peek(syn::Token![..]) {
                 let dot_token: syn::Token![.] = input.parse()?;
                 let member: Member = input.parse()?;
-                let turbofish = if member.is_named() && input.peek(syn::Token![::]) {
-                    Some(MethodTurbofish {
-                        colon2_token: input.parse()?,
-                        lt_token: input.parse()?,
-                        args: {
-                            let mut args = Punctuated::new();
-                            loop {
-                                if input.peek(syn::Token![>]) {
-                                    break;
-                                }
-                                let value = input.call(generic_method_argument)?;
-                                args.push_value(value);
-                                if input.peek(syn::Token![>]) {
-                                    break;
-                                }
-                                let punct = input.parse()?;
-                                args.push_punct(punct);
-                            }
-                            args
-                        },
-                        gt_token: input.parse()?,
-                    })
+                let turbofish = if member.is_named()
+                    && input.peek(syn::Token![::])
+                    && !input.peek3(syn::token::Paren)
+                {
+                    Some(input.call(method_turbofish)?)
                 } else {
                     None
                 };
@@ -1438,6 +1477,32 @@ pub mod parsing {
         Ok(e)
     }
 
+    // ::<..>
+    #[cfg(feature = "full")]
+    pub fn method_turbofish(input: ParseStream) -> Result<MethodTurbofish> {
+        Ok(MethodTurbofish {
+            colon2_token: input.parse()?,
+            lt_token: input.parse()?,
+            args: {
+                let mut args = Punctuated::new();
+                loop {
+                    if input.peek(syn::Token![>]) {
+                        break;
+                    }
+                    let value = input.call(generic_method_argument)?;
+                    args.push_value(value);
+                    if input.peek(syn::Token![>]) {
+                        break;
+                    }
+                    let punct = input.parse()?;
+                    args.push_punct(punct);
+                }
+                args
+            },
+            gt_token: input.parse()?,
+        })
+    }
+
     #[cfg(not(feature = "full"))]
     fn trailer_expr(input: ParseStream, allow_struct: AllowStruct) -> Result<Expr> {
         let mut e = atom_expr(input, allow_struct)?;
@@ -2019,6 +2084,16 @@ pub mod parsing {
 
     #[cfg(feature = "full")]
     fn expr_closure(input: ParseStream, allow_struct: AllowStruct) -> Result<ExprClosure> {
+        closure(input, |input| ambiguous_expr(input, allow_struct))
+    }
+
+    // Parses a closure whose body, unless it has a return type, is read by
+    // `body`.
+    #[cfg(feature = "full")]
+    fn closure(
+        input: ParseStream,
+        body: impl Fn(ParseStream) -> Result<Expr>,
+    ) -> Result<ExprClosure> {
         let asyncness: Option<syn::Token![async]> = input.parse()?;
         let movability: Option<syn::Token![static]> = if asyncness.is_none() {
             input.parse()?
@@ -2056,7 +2131,7 @@ pub mod parsing {
             });
             (output, block)
         } else {
-            let body = ambiguous_expr(input, allow_struct)?;
+            let body = body(input)?;
             (syn::ReturnType::Default, body)
         };
 
@@ -3139,6 +3214,34 @@ mod printing {
         }
     }
 
+    // If the given expression wouldn't bind as tightly as a method call,
+    // wraps it in parenthesis before appending it to `TokenStream`.
+    #[cfg(feature = "full")]
+    pub fn wrap_receiver(tokens: &mut TokenStream, e: &Expr) {
+        if binds_as_trailer(e) {
+            e.to_tokens(tokens);
+        } else {
+            syn::token::Paren::default().surround(tokens, |tokens| {
+                e.to_tokens(tokens);
+            });
+        }
+    }
+
+    // If the given expression is a turboball printed as an infix operation,
+    // such as a cast, wraps it in parenthesis so that it can be the operand
+    // of a prefix mark.
+    #[cfg(all(feature = "full", not(feature = "canonicalize")))]
+    fn wrap_operand(tokens: &mut TokenStream, e: &Expr) {
+        match *e {
+            Expr::Turboball(ref turboball) if turboball.expr_mark.is_infix() => {
+                syn::token::Paren::default().surround(tokens, |tokens| {
+                    e.to_tokens(tokens);
+                });
+            }
+            _ => e.to_tokens(tokens),
+        }
+    }
+
     #[cfg(feature = "full")]
     pub fn outer_attrs_to_tokens(attrs: &[syn::Attribute], tokens: &mut TokenStream) {
         tokens.append_all(attrs.outer());
@@ -3656,16 +3759,30 @@ mod printing {
         }
     }
 
-    #[cfg(feature = "full")]
+    #[cfg(all(feature = "full", not(feature = "canonicalize")))]
     impl ToTokens for ExprTurboball {
         fn to_tokens(&self, tokens: &mut TokenStream) {
+            // there is nothing to reorder if the mark isn't a prefix
+            if !self.expr_mark.is_prefix() {
+                self.to_classic().to_tokens(tokens);
+                return;
+            }
             outer_attrs_to_tokens(&self.attrs, tokens);
             self.expr_mark.to_tokens(tokens);
-            self.expr.to_tokens(tokens);
+            wrap_operand(tokens, &self.expr);
             self.post_mark.to_tokens(tokens);
         }
     }
 
+    // Prints the turboball through its classic expression instead of
+    // reordering its tokens.
+    #[cfg(all(feature = "full", feature = "canonicalize"))]
+    impl ToTokens for ExprTurboball {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.to_classic().to_tokens(tokens);
+        }
+    }
+
     impl ToTokens for ExprVerbatim {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             self.tts.to_tokens(tokens);
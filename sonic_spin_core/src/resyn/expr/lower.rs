@@ -0,0 +1,234 @@
+//! Lowers the resyn syntax tree into syn's, so that a parsed turboball can
+//! be handed to code that expects the standard syntax tree.
+//!
+//! Every turboball already prints as its classic syntax, so each expression
+//! is printed and then reparsed by syn. Statements and blocks are lowered
+//! node by node, so they keep their shape.
+//!
+//! syn has no await expression and would read `fut.await` as a field access
+//! named `await`, so an expression that awaits is kept as verbatim tokens.
+//! Any other expression that syn can't reparse is returned as its error.
+
+use crate::resyn::expr::{Block, Expr, Local, Pat, Stmt};
+use proc_macro2::{TokenStream, TokenTree};
+use quote::quote;
+use std::convert::TryFrom;
+
+impl TryFrom<Expr> for syn::Expr {
+    type Error = syn::Error;
+
+    fn try_from(expr: Expr) -> syn::Result<Self> {
+        let tts = quote!(#expr);
+        if has_await(tts.clone()) {
+            return Ok(syn::Expr::Verbatim(syn::ExprVerbatim { tts }));
+        }
+        syn::parse2(tts)
+    }
+}
+
+impl TryFrom<Pat> for syn::Pat {
+    type Error = syn::Error;
+
+    fn try_from(pat: Pat) -> syn::Result<Self> {
+        syn::parse2(quote!(#pat))
+    }
+}
+
+impl TryFrom<Local> for syn::Local {
+    type Error = syn::Error;
+
+    fn try_from(local: Local) -> syn::Result<Self> {
+        let init = match local.init {
+            Some((eq_token, init)) => Some((eq_token, Box::new(syn::Expr::try_from(*init)?))),
+            None => None,
+        };
+        Ok(syn::Local {
+            attrs: local.attrs,
+            let_token: local.let_token,
+            pats: local
+                .pats
+                .into_pairs()
+                .map(lower_pair)
+                .collect::<syn::Result<_>>()?,
+            ty: local.ty,
+            init,
+            semi_token: local.semi_token,
+        })
+    }
+}
+
+impl TryFrom<Stmt> for syn::Stmt {
+    type Error = syn::Error;
+
+    fn try_from(stmt: Stmt) -> syn::Result<Self> {
+        Ok(match stmt {
+            Stmt::Local(local) => syn::Stmt::Local(syn::Local::try_from(local)?),
+            Stmt::Item(item) => syn::Stmt::Item(item),
+            Stmt::Expr(expr) => syn::Stmt::Expr(syn::Expr::try_from(expr)?),
+            Stmt::Semi(expr, semi) => syn::Stmt::Semi(syn::Expr::try_from(expr)?, semi),
+        })
+    }
+}
+
+impl TryFrom<Block> for syn::Block {
+    type Error = syn::Error;
+
+    fn try_from(block: Block) -> syn::Result<Self> {
+        Ok(syn::Block {
+            brace_token: block.brace_token,
+            stmts: block
+                .stmts
+                .into_iter()
+                .map(syn::Stmt::try_from)
+                .collect::<syn::Result<_>>()?,
+        })
+    }
+}
+
+fn lower_pair(
+    pair: syn::punctuated::Pair<Pat, syn::Token![|]>,
+) -> syn::Result<syn::punctuated::Pair<syn::Pat, syn::Token![|]>> {
+    Ok(match pair {
+        syn::punctuated::Pair::Punctuated(pat, or) => {
+            syn::punctuated::Pair::Punctuated(syn::Pat::try_from(pat)?, or)
+        }
+        syn::punctuated::Pair::End(pat) => syn::punctuated::Pair::End(syn::Pat::try_from(pat)?),
+    })
+}
+
+// Whether the tokens have a `.await`, at any depth.
+fn has_await(tts: TokenStream) -> bool {
+    let mut dot = false;
+    for tt in tts {
+        match tt {
+            TokenTree::Ident(ref ident) if dot && ident == "await" => return true,
+            TokenTree::Group(ref group) if has_await(group.stream()) => return true,
+            _ => {}
+        }
+        dot = match tt {
+            TokenTree::Punct(ref punct) => punct.as_char() == '.',
+            _ => false,
+        };
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lower_expr(tts: TokenStream) -> syn::Expr {
+        let expr: Expr = syn::parse2(tts).unwrap();
+        syn::Expr::try_from(expr).unwrap()
+    }
+
+    fn lower_stmt(tts: TokenStream) -> syn::Stmt {
+        let block: Block = syn::parse2(quote!({ #tts })).unwrap();
+        assert_eq!(block.stmts.len(), 1);
+        syn::Stmt::try_from(block.stmts.into_iter().next().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn expr_round_trip() {
+        let expr = lower_expr(quote!(cond::(if) { 1 } else { 2 }));
+        match expr {
+            syn::Expr::If(_) => {}
+            _ => panic!("expected an if expression"),
+        }
+        assert_eq!(
+            quote!(#expr).to_string(),
+            quote!(if cond { 1 } else { 2 }).to_string()
+        );
+    }
+
+    #[test]
+    fn expr_nested_round_trip() {
+        let expr = lower_expr(quote!(x::(as u8)::(box)));
+        match expr {
+            syn::Expr::Box(_) => {}
+            _ => panic!("expected a box expression"),
+        }
+        assert_eq!(quote!(#expr).to_string(), quote!(box (x as u8)).to_string());
+    }
+
+    #[test]
+    fn expr_group_keeps_precedence() {
+        // the invisible group keeps the cast as the operand of the minus,
+        // where `- x as i32` would be a cast of `-x`
+        let expr = lower_expr(quote!(-x::(as i32)::(group)));
+        match expr {
+            syn::Expr::Unary(ref unary) => match *unary.expr {
+                syn::Expr::Group(ref group) => match *group.expr {
+                    syn::Expr::Cast(_) => {}
+                    _ => panic!("expected a cast"),
+                },
+                _ => panic!("expected an invisible group"),
+            },
+            _ => panic!("expected a unary expression"),
+        }
+    }
+
+    #[test]
+    fn expr_await_is_verbatim() {
+        let expr = lower_expr(quote!(fut::(await)));
+        match expr {
+            syn::Expr::Verbatim(_) => {}
+            _ => panic!("expected verbatim tokens"),
+        }
+        assert_eq!(quote!(#expr).to_string(), quote!(fut.await).to_string());
+    }
+
+    #[test]
+    fn stmt_keeps_semi() {
+        let stmt = lower_stmt(quote!(x::(-);));
+        match stmt {
+            syn::Stmt::Semi(syn::Expr::Unary(_), _) => {}
+            _ => panic!("expected a unary expression with a semicolon"),
+        }
+        assert_eq!(quote!(#stmt).to_string(), quote!(-x;).to_string());
+    }
+
+    #[test]
+    fn stmt_keeps_local() {
+        let stmt = lower_stmt(quote!(let (a, b): (u8, u8) = x::(*);));
+        match stmt {
+            syn::Stmt::Local(ref local) => {
+                assert!(local.ty.is_some());
+                assert!(local.init.is_some());
+            }
+            _ => panic!("expected a let binding"),
+        }
+        assert_eq!(
+            quote!(#stmt).to_string(),
+            quote!(let (a, b): (u8, u8) = *x;).to_string()
+        );
+    }
+
+    #[test]
+    fn stmt_await_keeps_semi() {
+        let stmt = lower_stmt(quote!(fut::(await);));
+        match stmt {
+            syn::Stmt::Semi(syn::Expr::Verbatim(_), _) => {}
+            _ => panic!("expected verbatim tokens with a semicolon"),
+        }
+    }
+
+    #[test]
+    fn block_round_trip() {
+        let block: Block = syn::parse2(quote!({
+            let x = 1::(-);
+            x::(box)
+        }))
+        .unwrap();
+        let block = syn::Block::try_from(block).unwrap();
+        assert_eq!(block.stmts.len(), 2);
+        assert_eq!(
+            quote!(#block).to_string(),
+            quote!({
+                let x = -1;
+                box x
+            })
+            .to_string()
+        );
+    }
+}
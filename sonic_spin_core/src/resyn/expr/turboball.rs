@@ -0,0 +1,90 @@
+use crate::resyn::expr::{
+    parsing, Arm, Block, Expr, ExprBlock, ExprPath, ExprTuple, ExprTurboball, MethodTurbofish, Stmt,
+};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+
+mod classic;
+pub mod mark;
+pub mod post_mark;
+pub mod sugar;
+
+pub use mark::ExprMark;
+pub use post_mark::PostExprMark;
+use syn::parse::{ParseBuffer, Result};
+
+pub fn parse_turboball(input: &ParseBuffer, e: Expr) -> Result<Expr> {
+    let colon2_token: syn::Token![::] = input.parse()?;
+    let content;
+    let paren_token = syn::parenthesized!(content in input);
+    let expr_mark: ExprMark = content.parse()?;
+
+    let post_mark = match expr_mark {
+        ExprMark::If(_) => {
+            let mark: post_mark::If = input.parse()?;
+            Some(PostExprMark::If(mark))
+        }
+        ExprMark::While(_) => {
+            let mark: post_mark::While = input.parse()?;
+            Some(PostExprMark::While(mark))
+        }
+        ExprMark::ForLoop(_) => {
+            let mark: post_mark::ForLoop = input.parse()?;
+            Some(PostExprMark::ForLoop(mark))
+        }
+        ExprMark::Match(_) => {
+            let mark: post_mark::Match = input.parse()?;
+            Some(PostExprMark::Match(mark))
+        }
+        ExprMark::Continue(_) if !is_unit(&e) => {
+            let msg = "`continue` takes no value, so its receiver must be `()`";
+            return Err(syn::Error::new(e.span(), msg));
+        }
+        _ => None,
+    };
+
+    let e = match expr_mark {
+        ExprMark::Loop(_)
+        | ExprMark::Unsafe(_)
+        | ExprMark::Block(_)
+        | ExprMark::Async(_)
+        | ExprMark::TryBlock(_) => Expr::Block(ExprBlock {
+            attrs: Vec::new(),
+            label: None,
+            block: receiver_block(e),
+        }),
+        _ => e,
+    };
+
+    Ok(Expr::Turboball(ExprTurboball {
+        attrs: Vec::new(),
+        expr: Box::new(e),
+        colon2_token,
+        paren_token,
+        expr_mark,
+        post_mark,
+    }))
+}
+
+fn is_unit(e: &Expr) -> bool {
+    match *e {
+        Expr::Tuple(ExprTuple { ref elems, .. }) => elems.is_empty(),
+        _ => false,
+    }
+}
+
+// Uses the receiver of a block-taking mark (eg. `loop`) as its block,
+// bracing it unless it's already an unlabeled block.
+fn receiver_block(e: Expr) -> Block {
+    match e {
+        Expr::Block(ExprBlock {
+            attrs,
+            label: None,
+            block,
+        }) if attrs.is_empty() => block,
+        e => Block {
+            brace_token: syn::token::Brace::default(),
+            stmts: vec![Stmt::Expr(e)],
+        },
+    }
+}
@@ -0,0 +1,457 @@
+use crate::resyn::expr::turboball::{mark, receiver_block, ExprMark, PostExprMark};
+use crate::resyn::expr::{
+    binds_as_trailer, Expr, ExprAssign, ExprAssignOp, ExprAsync, ExprBlock, ExprBox, ExprBreak,
+    ExprCall, ExprCast, ExprContinue, ExprForLoop, ExprGroup, ExprIf, ExprLet, ExprLoop, ExprMacro,
+    ExprMatch, ExprMethodCall, ExprParen, ExprReference, ExprReturn, ExprTry, ExprTryBlock,
+    ExprTurboball, ExprType, ExprUnary, ExprUnsafe, ExprVerbatim, ExprWhile, ExprYield,
+};
+use crate::resyn::Macro;
+
+#[cfg(feature = "full")]
+impl ExprTurboball {
+    /// Rebuilds the classic expression that this turboball stands for,
+    /// eg. `cond::(if) {..}` becomes `if cond {..}`.
+    ///
+    /// Printing the classic expression yields the same tokens as the
+    /// reordered turboball, so this is what the `canonicalize` feature
+    /// prints.
+    pub fn to_classic(&self) -> Expr {
+        let attrs = self.attrs.clone();
+        let expr = operand(&self.expr);
+        match (&self.expr_mark, &self.post_mark) {
+            (ExprMark::Box(mark), None) => Expr::Box(ExprBox {
+                attrs,
+                box_token: mark.box_token,
+                expr,
+            }),
+            (ExprMark::Unary(mark), None) => Expr::Unary(ExprUnary {
+                attrs,
+                op: mark.op,
+                expr,
+            }),
+            (ExprMark::Cast(mark), None) => Expr::Cast(ExprCast {
+                attrs,
+                expr,
+                as_token: mark.as_token,
+                ty: mark.ty.clone(),
+            }),
+            (ExprMark::Type(mark), None) => Expr::Type(ExprType {
+                attrs,
+                expr,
+                colon_token: mark.colon_token,
+                ty: mark.ty.clone(),
+            }),
+            (ExprMark::Let(mark), None) => Expr::Let(ExprLet {
+                attrs,
+                let_token: mark.let_token,
+                pats: mark.pats.clone(),
+                eq_token: mark.eq_token,
+                expr,
+            }),
+            (ExprMark::If(mark), Some(PostExprMark::If(post))) => Expr::If(ExprIf {
+                attrs,
+                if_token: mark.if_token,
+                cond: let_cond(&mark.let_mark, expr),
+                then_branch: post.then_branch.clone(),
+                else_branch: post.else_branch.clone(),
+            }),
+            (ExprMark::While(mark), Some(PostExprMark::While(post))) => Expr::While(ExprWhile {
+                attrs: syn::private::attrs(attrs, post.attrs.clone()),
+                label: mark.label.clone(),
+                while_token: mark.while_token,
+                cond: let_cond(&mark.let_mark, expr),
+                body: post.body.clone(),
+            }),
+            (ExprMark::ForLoop(mark), Some(PostExprMark::ForLoop(post))) => {
+                Expr::ForLoop(ExprForLoop {
+                    attrs: syn::private::attrs(attrs, post.attrs.clone()),
+                    label: mark.label.clone(),
+                    for_token: mark.for_token,
+                    pat: mark.pat.clone(),
+                    in_token: mark.in_token,
+                    expr,
+                    body: post.body.clone(),
+                })
+            }
+            (ExprMark::Loop(mark), None) => Expr::Loop(ExprLoop {
+                attrs,
+                label: mark.label.clone(),
+                loop_token: mark.loop_token,
+                body: receiver_block((*self.expr).clone()),
+            }),
+            (ExprMark::Match(mark), Some(PostExprMark::Match(post))) => Expr::Match(ExprMatch {
+                attrs: syn::private::attrs(attrs, post.attrs.clone()),
+                match_token: mark.match_token,
+                expr,
+                brace_token: post.brace_token,
+                arms: post.arms.clone(),
+            }),
+            (ExprMark::Unsafe(mark), None) => Expr::Unsafe(ExprUnsafe {
+                attrs,
+                unsafe_token: mark.unsafe_token,
+                block: receiver_block((*self.expr).clone()),
+            }),
+            (ExprMark::Block(mark), None) => Expr::Block(ExprBlock {
+                attrs,
+                label: mark.label.clone(),
+                block: receiver_block((*self.expr).clone()),
+            }),
+            (ExprMark::Assign(mark), None) => Expr::Assign(ExprAssign {
+                attrs,
+                left: expr,
+                eq_token: mark.eq_token,
+                right: mark.right.clone(),
+            }),
+            (ExprMark::AssignOp(mark), None) => Expr::AssignOp(ExprAssignOp {
+                attrs,
+                left: expr,
+                op: mark.op,
+                right: mark.right.clone(),
+            }),
+            (ExprMark::Reference(mark), None) => Expr::Reference(ExprReference {
+                attrs,
+                and_token: mark.and_token,
+                mutability: mark.mutability,
+                expr,
+            }),
+            (ExprMark::Break(mark), None) => Expr::Break(ExprBreak {
+                attrs,
+                break_token: mark.break_token,
+                label: mark.label.clone(),
+                expr: Some(expr),
+            }),
+            (ExprMark::Continue(mark), None) => Expr::Continue(ExprContinue {
+                attrs,
+                continue_token: mark.continue_token,
+                label: mark.label.clone(),
+            }),
+            (ExprMark::Return(mark), None) => Expr::Return(ExprReturn {
+                attrs,
+                return_token: mark.return_token,
+                expr: Some(expr),
+            }),
+            (ExprMark::Macro(mark), None) => Expr::Macro(ExprMacro {
+                attrs,
+                mac: receiver_macro(&mark.mac, &self.expr),
+            }),
+            (ExprMark::Paren(mark), None) => Expr::Paren(ExprParen {
+                attrs,
+                paren_token: syn::token::Paren(mark.paren_token.span()),
+                expr: self.expr.clone(),
+            }),
+            (ExprMark::Group(mark), None) => Expr::Group(ExprGroup {
+                attrs,
+                group_token: syn::token::Group(mark.group_token.span()),
+                expr: self.expr.clone(),
+            }),
+            (ExprMark::Async(mark), None) => Expr::Async(ExprAsync {
+                attrs,
+                async_token: mark.async_token,
+                capture: mark.capture,
+                block: receiver_block((*self.expr).clone()),
+            }),
+            (ExprMark::TryBlock(mark), None) => Expr::TryBlock(ExprTryBlock {
+                attrs,
+                try_token: mark.try_token,
+                block: receiver_block((*self.expr).clone()),
+            }),
+            (ExprMark::Try(mark), None) => Expr::Try(ExprTry {
+                attrs,
+                expr: trailer_receiver(&self.expr),
+                question_token: mark.question_token,
+            }),
+            (ExprMark::Yield(mark), None) => Expr::Yield(ExprYield {
+                attrs,
+                yield_token: mark.yield_token,
+                expr: Some(expr),
+            }),
+            (ExprMark::Await(mark), None) => Expr::Verbatim(ExprVerbatim {
+                tts: mark.lower(&self.expr),
+            }),
+            (ExprMark::Sugar(mark), None) => Expr::Verbatim(ExprVerbatim {
+                tts: mark.lower(&self.expr),
+            }),
+            (ExprMark::Call(mark), None) => Expr::Call(ExprCall {
+                attrs,
+                func: Box::new(Expr::Path(mark.func.clone())),
+                paren_token: syn::token::Paren::default(),
+                args: vec![(*self.expr).clone()].into_iter().collect(),
+            }),
+            (ExprMark::MethodCall(mark), None) => Expr::MethodCall(ExprMethodCall {
+                attrs,
+                receiver: trailer_receiver(&self.expr),
+                dot_token: mark.dot_token,
+                method: mark.method.clone(),
+                turbofish: mark.turbofish.clone(),
+                paren_token: mark.paren_token,
+                args: mark.args.clone(),
+            }),
+            _ => unreachable!("post mark doesn't belong to the turboball mark"),
+        }
+    }
+}
+
+// Uses the receiver as the operand of a mark, parenthesizing it if it's
+// printed as an infix operation (eg. a cast), which would bind looser.
+#[cfg(feature = "full")]
+fn operand(expr: &Expr) -> Box<Expr> {
+    let expr = match *expr {
+        Expr::Turboball(ref turboball) if turboball.expr_mark.is_infix() => {
+            Expr::Paren(ExprParen {
+                attrs: Vec::new(),
+                paren_token: syn::token::Paren::default(),
+                expr: Box::new(expr.clone()),
+            })
+        }
+        _ => expr.clone(),
+    };
+    Box::new(expr)
+}
+
+// Uses the receiver as the condition of an `if` or `while` mark, binding it
+// to the patterns if the mark has a `let`.
+#[cfg(feature = "full")]
+fn let_cond(let_mark: &Option<mark::Let>, expr: Box<Expr>) -> Box<Expr> {
+    match *let_mark {
+        Some(ref let_mark) => Box::new(Expr::Let(ExprLet {
+            attrs: Vec::new(),
+            let_token: let_mark.let_token,
+            pats: let_mark.pats.clone(),
+            eq_token: let_mark.eq_token,
+            expr,
+        })),
+        None => expr,
+    }
+}
+
+// Uses the receiver as the left side of a postfix mark (eg. a method call),
+// parenthesizing it if it wouldn't bind as tightly.
+#[cfg(feature = "full")]
+fn trailer_receiver(expr: &Expr) -> Box<Expr> {
+    let expr = if binds_as_trailer(expr) {
+        expr.clone()
+    } else {
+        Expr::Paren(ExprParen {
+            attrs: Vec::new(),
+            paren_token: syn::token::Paren::default(),
+            expr: Box::new(expr.clone()),
+        })
+    };
+    Box::new(expr)
+}
+
+// Passes the receiver as the first argument of a macro mark (eg. `dbg!`),
+// before the arguments that the mark already has.
+#[cfg(feature = "full")]
+fn receiver_macro(mac: &Macro, expr: &Expr) -> Macro {
+    let mut mac = mac.clone();
+    let args = &mac.tts;
+    mac.tts = if args.is_empty() {
+        quote::quote!(#expr)
+    } else {
+        quote::quote!(#expr, #args)
+    };
+    mac
+}
+
+// These pass both with and without the `canonicalize` feature, since either
+// way a turboball prints as its classic tokens.
+#[cfg(test)]
+mod tests {
+    use crate::resyn::expr::Expr;
+    use proc_macro2::{Delimiter, Group, TokenStream};
+    use quote::{quote, ToTokens};
+
+    fn assert_classic(turboball: TokenStream, classic: TokenStream) {
+        let expr: Expr = syn::parse2(turboball).unwrap();
+        match expr {
+            Expr::Turboball(_) => {}
+            _ => panic!("expected a turboball"),
+        }
+        assert_eq!(expr.into_token_stream().to_string(), classic.to_string());
+    }
+
+    #[test]
+    fn classic_box() {
+        assert_classic(quote!(x::(box)), quote!(box x));
+    }
+
+    #[test]
+    fn classic_unary() {
+        assert_classic(quote!(x::(-)), quote!(-x));
+        assert_classic(quote!(x::(!)), quote!(!x));
+        assert_classic(quote!(x::(*)), quote!(*x));
+    }
+
+    #[test]
+    fn classic_cast() {
+        assert_classic(quote!(x::(as u8)), quote!(x as u8));
+        assert_classic(quote!(x::(as u8)::(-)), quote!(-(x as u8)));
+    }
+
+    #[test]
+    fn classic_type() {
+        assert_classic(quote!(x::(: u8)), quote!(x: u8));
+    }
+
+    #[test]
+    fn classic_let() {
+        assert_classic(quote!(x::(let y =)), quote!(let y = x));
+    }
+
+    #[test]
+    fn classic_if() {
+        assert_classic(
+            quote!(c::(if) { 1 } else { 2 }),
+            quote!(if c { 1 } else { 2 }),
+        );
+    }
+
+    #[test]
+    fn classic_if_let() {
+        assert_classic(
+            quote!(x::(if let Some(y) =) { y }),
+            quote!(if let Some(y) = x { y }),
+        );
+    }
+
+    #[test]
+    fn classic_while() {
+        assert_classic(quote!(c::(while) { f() }), quote!(while c { f() }));
+        assert_classic(quote!(c::('a: while) {}), quote!('a: while c {}));
+    }
+
+    #[test]
+    fn classic_while_let() {
+        assert_classic(
+            quote!(it.next()::(while let Some(y) =) { f(y) }),
+            quote!(while let Some(y) = it.next() { f(y) }),
+        );
+    }
+
+    #[test]
+    fn classic_for() {
+        assert_classic(quote!(v::(for x in) { f(x) }), quote!(for x in v { f(x) }));
+        assert_classic(quote!(v::('a: for x in) {}), quote!('a: for x in v {}));
+    }
+
+    #[test]
+    fn classic_loop() {
+        assert_classic(quote!({ f() }::(loop)), quote!(loop { f() }));
+        assert_classic(quote!({ f() }::('a: loop)), quote!('a: loop { f() }));
+        assert_classic(quote!(f()::('a: loop)), quote!('a: loop { f() }));
+    }
+
+    #[test]
+    fn classic_match() {
+        assert_classic(quote!(x::(match) { _ => 1 }), quote!(match x { _ => 1 }));
+    }
+
+    #[test]
+    fn classic_unsafe() {
+        assert_classic(quote!({ f() }::(unsafe)), quote!(unsafe { f() }));
+    }
+
+    #[test]
+    fn classic_block() {
+        assert_classic(quote!({ f() }::('a:)), quote!('a: { f() }));
+        assert_classic(quote!(f()::('a:)), quote!('a: { f() }));
+    }
+
+    #[test]
+    fn classic_assign() {
+        assert_classic(quote!(x::(= 1)), quote!(x = 1));
+    }
+
+    #[test]
+    fn classic_assign_op() {
+        assert_classic(quote!(x::(+= 1)), quote!(x += 1));
+        assert_classic(quote!(x::(-= 1)), quote!(x -= 1));
+    }
+
+    #[test]
+    fn classic_reference() {
+        assert_classic(quote!(x::(&)), quote!(&x));
+        assert_classic(quote!(x::(&mut)), quote!(&mut x));
+    }
+
+    #[test]
+    fn classic_break() {
+        assert_classic(quote!(x::(break)), quote!(break x));
+        assert_classic(quote!(x::(break 'a)), quote!(break 'a x));
+    }
+
+    #[test]
+    fn classic_continue() {
+        assert_classic(quote!(()::(continue)), quote!(continue));
+        assert_classic(quote!(()::(continue 'a)), quote!(continue 'a));
+    }
+
+    #[test]
+    fn classic_return() {
+        assert_classic(quote!(x::(return)), quote!(return x));
+    }
+
+    #[test]
+    fn classic_macro() {
+        assert_classic(quote!(x::(dbg!)), quote!(dbg!(x)));
+        assert_classic(quote!(x::(assert_eq!(1))), quote!(assert_eq!(x, 1)));
+    }
+
+    #[test]
+    fn classic_paren() {
+        assert_classic(quote!(x::(paren)), quote!((x)));
+    }
+
+    #[test]
+    fn classic_group() {
+        let group = Group::new(Delimiter::None, quote!(x));
+        assert_classic(quote!(x::(group)), group.into_token_stream());
+    }
+
+    #[test]
+    fn classic_async() {
+        assert_classic(quote!({ f() }::(async)), quote!(async { f() }));
+        assert_classic(quote!({ f() }::(async move)), quote!(async move { f() }));
+    }
+
+    #[test]
+    fn classic_try_block() {
+        assert_classic(quote!({ f()? }::(try)), quote!(try { f()? }));
+    }
+
+    #[test]
+    fn classic_try() {
+        assert_classic(quote!(f()::(?)), quote!(f()?));
+        assert_classic(quote!(x::(-)::(?)), quote!((-x)?));
+    }
+
+    #[test]
+    fn classic_yield() {
+        assert_classic(quote!(x::(yield)), quote!(yield x));
+    }
+
+    #[test]
+    fn classic_await() {
+        assert_classic(quote!(f()::(await)), quote!(f().await));
+    }
+
+    #[cfg(feature = "sugars")]
+    #[test]
+    fn classic_sugar() {
+        assert_classic(quote!(x::(into)), quote!(x.into()));
+        assert_classic(quote!(v::(fold 0 f)), quote!(v.fold(0, f)));
+    }
+
+    #[test]
+    fn classic_call() {
+        assert_classic(quote!(x::(f)), quote!(f(x)));
+        assert_classic(quote!(x::(a::b)), quote!(a::b(x)));
+    }
+
+    #[test]
+    fn classic_method_call() {
+        assert_classic(quote!(x::(.f(1))), quote!(x.f(1)));
+        assert_classic(quote!(x::(-)::(.f())), quote!((-x).f()));
+    }
+}
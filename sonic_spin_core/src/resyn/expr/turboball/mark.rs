@@ -0,0 +1,243 @@
+mod parse;
+mod quote;
+
+use super::*;
+
+#[derive(Clone)]
+pub enum ExprMark {
+    Box(mark::MarkBox),
+    Unary(mark::Unary),
+    Cast(mark::Cast),
+    Type(mark::Type),
+    Let(mark::Let),
+    If(mark::If),
+    While(mark::While),
+    ForLoop(mark::ForLoop),
+    Loop(mark::Loop),
+    Match(mark::Match),
+    Unsafe(mark::Unsafe),
+    Block(mark::Block),
+    Assign(mark::Assign),
+    AssignOp(mark::AssignOp),
+    Reference(mark::Reference),
+    Break(mark::Break),
+    Continue(mark::Continue),
+    Return(mark::Return),
+    Macro(mark::Macro),
+    Paren(mark::Paren),
+    Group(mark::Group),
+    Async(mark::Async),
+    TryBlock(mark::TryBlock),
+    Try(mark::Try),
+    Yield(mark::Yield),
+    Await(mark::Await),
+    Sugar(mark::Sugar),
+    Call(mark::Call),
+    MethodCall(mark::MethodCall),
+}
+
+impl ExprMark {
+    /// Whether the mark is the prefix of its classic syntax, so that it can
+    /// be printed by just moving it before the receiver.
+    pub fn is_prefix(&self) -> bool {
+        !matches!(
+            self,
+            ExprMark::Assign(_)
+                | ExprMark::AssignOp(_)
+                | ExprMark::Cast(_)
+                | ExprMark::Type(_)
+                | ExprMark::Continue(_)
+                | ExprMark::Macro(_)
+                | ExprMark::Paren(_)
+                | ExprMark::Group(_)
+                | ExprMark::Try(_)
+                | ExprMark::Await(_)
+                | ExprMark::Sugar(_)
+                | ExprMark::Call(_)
+                | ExprMark::MethodCall(_)
+        )
+    }
+
+    /// Whether the mark is printed as an infix operator after the receiver,
+    /// such as a cast, and so binds looser than prefix operators.
+    pub fn is_infix(&self) -> bool {
+        matches!(
+            self,
+            ExprMark::Assign(_) | ExprMark::AssignOp(_) | ExprMark::Cast(_) | ExprMark::Type(_)
+        )
+    }
+}
+
+#[derive(Clone)]
+pub struct MarkBox {
+    pub box_token: syn::Token![box],
+}
+
+#[derive(Clone)]
+pub struct Unary {
+    pub op: syn::UnOp,
+}
+
+#[derive(Clone)]
+pub struct Cast {
+    pub as_token: syn::Token![as],
+    pub ty: Box<syn::Type>,
+}
+
+#[derive(Clone)]
+pub struct Type {
+    pub colon_token: syn::Token![:],
+    pub ty: Box<syn::Type>,
+}
+
+#[derive(Clone)]
+pub struct Let {
+    pub let_token: syn::Token![let],
+    pub pats: Punctuated<syn::Pat, syn::Token![|]>,
+    pub eq_token: syn::Token![=], // maybe remove
+}
+
+#[derive(Clone)]
+pub struct If {
+    pub if_token: syn::Token![if],
+    /// The `let pats =` of an `if let`, whose scrutinee is the receiver.
+    pub let_mark: Option<Let>,
+}
+
+#[derive(Clone)]
+pub struct While {
+    pub label: Option<syn::Label>,
+    pub while_token: syn::Token![while],
+    /// The `let pats =` of a `while let`, whose scrutinee is the receiver.
+    pub let_mark: Option<Let>,
+}
+
+#[derive(Clone)]
+pub struct ForLoop {
+    pub label: Option<syn::Label>,
+    pub for_token: syn::Token![for],
+    pub pat: Box<syn::Pat>,
+    pub in_token: syn::Token![in],
+}
+
+#[derive(Clone)]
+pub struct Loop {
+    pub label: Option<syn::Label>,
+    pub loop_token: syn::Token![loop],
+}
+
+#[derive(Clone)]
+pub struct Match {
+    pub match_token: syn::Token![match],
+}
+
+#[derive(Clone)]
+pub struct Unsafe {
+    pub unsafe_token: syn::Token![unsafe],
+}
+
+#[derive(Clone)]
+pub struct Block {
+    pub label: Option<syn::Label>,
+}
+
+#[derive(Clone)]
+pub struct Assign {
+    pub eq_token: syn::Token![=],
+    pub right: Box<Expr>,
+}
+
+#[derive(Clone)]
+pub struct AssignOp {
+    pub op: syn::BinOp,
+    pub right: Box<Expr>,
+}
+
+#[derive(Clone)]
+pub struct Reference {
+    pub and_token: syn::Token![&],
+    pub mutability: Option<syn::Token![mut]>,
+}
+
+#[derive(Clone)]
+pub struct Break {
+    pub break_token: syn::Token![break],
+    pub label: Option<syn::Lifetime>,
+}
+
+#[derive(Clone)]
+pub struct Continue {
+    pub continue_token: syn::Token![continue],
+    pub label: Option<syn::Lifetime>,
+}
+
+#[derive(Clone)]
+pub struct Return {
+    pub return_token: syn::Token![return],
+}
+
+/// `paren` is only a contextual keyword, so it's kept as an ident.
+#[derive(Clone)]
+pub struct Paren {
+    pub paren_token: syn::Ident,
+}
+
+/// `group` is only a contextual keyword, so it's kept as an ident.
+#[derive(Clone)]
+pub struct Group {
+    pub group_token: syn::Ident,
+}
+
+#[derive(Clone)]
+pub struct Async {
+    pub async_token: syn::Token![async],
+    pub capture: Option<syn::Token![move]>,
+}
+
+#[derive(Clone)]
+pub struct TryBlock {
+    pub try_token: syn::Token![try],
+}
+
+#[derive(Clone)]
+pub struct Try {
+    pub question_token: syn::Token![?],
+}
+
+#[derive(Clone)]
+pub struct Yield {
+    pub yield_token: syn::Token![yield],
+}
+
+/// `await` is only a contextual keyword here, so it's kept as an ident.
+#[derive(Clone)]
+pub struct Await {
+    pub await_token: syn::Ident,
+}
+
+#[derive(Clone)]
+pub struct Sugar {
+    pub name: syn::Ident,
+    pub turbofish: Option<syn::Type>,
+    pub args: Vec<Expr>,
+    pub lowering: sugar::Lowering,
+}
+
+#[derive(Clone)]
+pub struct Call {
+    pub func: ExprPath,
+}
+
+#[derive(Clone)]
+pub struct MethodCall {
+    pub dot_token: syn::Token![.],
+    pub method: syn::Ident,
+    pub turbofish: Option<MethodTurbofish>,
+    pub paren_token: syn::token::Paren,
+    pub args: Punctuated<Expr, syn::Token![,]>,
+}
+
+#[derive(Clone)]
+pub struct Macro {
+    pub mac: crate::resyn::Macro,
+}
@@ -0,0 +1,372 @@
+use crate::resyn::expr::turboball::mark;
+use crate::resyn::expr::turboball::sugar;
+use crate::resyn::expr::turboball::ExprMark;
+use crate::resyn::expr::{parsing, Expr, ExprPath};
+use syn::punctuated::Punctuated;
+
+#[cfg(feature = "full")]
+impl syn::parse::Parse for ExprMark {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        // compound assignments go first, since `&=`, `*=` and `-=` would
+        // otherwise be read as prefix operators
+        let mark = if let Some(op) = assign_op(input)? {
+            let right: Expr = input.parse()?;
+            let right = Box::new(right);
+            let mark = mark::AssignOp { op, right };
+            ExprMark::AssignOp(mark)
+        } else if input.peek(syn::Token![&]) {
+            let and_token = input.parse()?;
+            let mutability = input.parse()?;
+            let mark = mark::Reference {
+                and_token,
+                mutability,
+            };
+            ExprMark::Reference(mark)
+        } else if input.peek(syn::Token![box]) {
+            let box_token = input.parse()?;
+            let mark = mark::MarkBox { box_token };
+            ExprMark::Box(mark)
+        } else if input.peek(syn::Token![<-]) {
+            // `place <- value` would now be read as `place < -value`
+            let arrow_token: syn::Token![<-] = input.parse()?;
+            let msg = "placement syntax was removed, so `::(<- value)` has no lowering";
+            return Err(syn::Error::new_spanned(arrow_token, msg));
+        } else if input.peek(syn::Token![*])
+            || input.peek(syn::Token![!])
+            || input.peek(syn::Token![-])
+        {
+            let op = input.parse()?;
+            let mark = mark::Unary { op };
+            ExprMark::Unary(mark)
+        } else if input.peek(syn::Token![as]) {
+            let as_token = input.parse()?;
+            let ty = input.call(syn::Type::without_plus)?;
+            let ty = Box::new(ty);
+            let mark = mark::Cast { as_token, ty };
+            ExprMark::Cast(mark)
+        } else if input.peek(syn::Token![:]) && !input.peek(syn::Token![::]) {
+            let colon_token = input.parse()?;
+            let ty = input.call(syn::Type::without_plus)?;
+            let ty = Box::new(ty);
+            let mark = mark::Type { colon_token, ty };
+            ExprMark::Type(mark)
+        } else if input.peek(syn::Token![let]) {
+            let mark = let_mark(input)?;
+            ExprMark::Let(mark)
+        } else if input.peek(syn::Token![if]) {
+            let if_token = input.parse()?;
+            let let_mark = cond_let(input)?;
+            let mark = mark::If { if_token, let_mark };
+            ExprMark::If(mark)
+        } else if input.peek(syn::Lifetime) {
+            let label: syn::Label = input.parse()?;
+            let label = Some(label);
+            if input.peek(syn::Token![while]) {
+                let while_token = input.parse()?;
+                let let_mark = cond_let(input)?;
+                let mark = mark::While {
+                    label,
+                    while_token,
+                    let_mark,
+                };
+                ExprMark::While(mark)
+            } else if input.peek(syn::Token![for]) {
+                let for_token = input.parse()?;
+                let pat: syn::Pat = input.parse()?;
+                let pat = Box::new(pat);
+                let in_token: syn::Token![in] = input.parse()?;
+                let mark = mark::ForLoop {
+                    label,
+                    for_token,
+                    pat,
+                    in_token,
+                };
+                ExprMark::ForLoop(mark)
+            } else if input.peek(syn::Token![loop]) {
+                let loop_token = input.parse()?;
+                let mark = mark::Loop { label, loop_token };
+                ExprMark::Loop(mark)
+            } else if input.peek(syn::Token![continue]) {
+                let continue_token = input.parse()?;
+                let label = label.map(|label| label.name);
+                let mark = mark::Continue {
+                    continue_token,
+                    label,
+                };
+                ExprMark::Continue(mark)
+            } else if input.is_empty() {
+                let mark = mark::Block { label };
+                ExprMark::Block(mark)
+            } else {
+                return Err(input.error("expected loop, continue or block expression"));
+            }
+        } else if input.peek(syn::Token![while]) {
+            let label = None;
+            let while_token = input.parse()?;
+            let let_mark = cond_let(input)?;
+            let mark = mark::While {
+                label,
+                while_token,
+                let_mark,
+            };
+            ExprMark::While(mark)
+        } else if input.peek(syn::Token![for]) {
+            let label = None;
+            let for_token = input.parse()?;
+            let pat: syn::Pat = input.parse()?;
+            let pat = Box::new(pat);
+            let in_token: syn::Token![in] = input.parse()?;
+            let mark = mark::ForLoop {
+                label,
+                for_token,
+                pat,
+                in_token,
+            };
+            ExprMark::ForLoop(mark)
+        } else if input.peek(syn::Token![loop]) {
+            let label = None;
+            let loop_token = input.parse()?;
+            let mark = mark::Loop { label, loop_token };
+            ExprMark::Loop(mark)
+        } else if input.peek(syn::Token![match]) {
+            let match_token = input.parse()?;
+            let mark = mark::Match { match_token };
+            ExprMark::Match(mark)
+        } else if input.peek(syn::Token![unsafe]) {
+            let unsafe_token = input.parse()?;
+            let mark = mark::Unsafe { unsafe_token };
+            ExprMark::Unsafe(mark)
+        } else if input.peek(syn::Token![break]) {
+            let break_token = input.parse()?;
+            let label = input.parse()?;
+            let mark = mark::Break { break_token, label };
+            ExprMark::Break(mark)
+        } else if input.peek(syn::Token![continue]) {
+            let continue_token = input.parse()?;
+            let label = input.parse()?;
+            let mark = mark::Continue {
+                continue_token,
+                label,
+            };
+            ExprMark::Continue(mark)
+        } else if input.peek(syn::Token![return]) {
+            let return_token = input.parse()?;
+            let mark = mark::Return { return_token };
+            ExprMark::Return(mark)
+        } else if input.peek(syn::token::Group) {
+            // a marker interpolated from a `macro_rules!` fragment, such as
+            // `$f:path`, arrives wrapped in an invisible group
+            let group = syn::private::parse_group(input)?;
+            return group.content.parse();
+        } else if input.peek(syn::Token![async]) {
+            let async_token = input.parse()?;
+            let capture = input.parse()?;
+            let mark = mark::Async {
+                async_token,
+                capture,
+            };
+            ExprMark::Async(mark)
+        } else if input.peek(syn::Token![try]) {
+            let try_token = input.parse()?;
+            let mark = mark::TryBlock { try_token };
+            ExprMark::TryBlock(mark)
+        } else if input.peek(syn::Token![=])
+            && !input.peek(syn::Token![==])
+            && !input.peek(syn::Token![=>])
+        {
+            let eq_token = input.parse()?;
+            let right: Expr = input.parse()?;
+            let right = Box::new(right);
+            let mark = mark::Assign { eq_token, right };
+            ExprMark::Assign(mark)
+        } else if input.peek(syn::Token![?]) {
+            let question_token = input.parse()?;
+            let mark = mark::Try { question_token };
+            ExprMark::Try(mark)
+        } else if input.peek(syn::Token![yield]) {
+            let yield_token = input.parse()?;
+            let mark = mark::Yield { yield_token };
+            ExprMark::Yield(mark)
+        } else if peek_keyword(input, "paren") {
+            let paren_token = input.parse()?;
+            let mark = mark::Paren { paren_token };
+            ExprMark::Paren(mark)
+        } else if peek_keyword(input, "group") {
+            let group_token = input.parse()?;
+            let mark = mark::Group { group_token };
+            ExprMark::Group(mark)
+        } else if peek_keyword(input, "await") {
+            let await_token = input.parse()?;
+            let mark = mark::Await { await_token };
+            ExprMark::Await(mark)
+        } else if input.peek(syn::Token![.]) {
+            let dot_token = input.parse()?;
+            let method = input.parse()?;
+            let turbofish = if input.peek(syn::Token![::]) {
+                Some(input.call(parsing::method_turbofish)?)
+            } else {
+                None
+            };
+            let content;
+            let paren_token = syn::parenthesized!(content in input);
+            let args = content.parse_terminated(Expr::parse)?;
+            let mark = mark::MethodCall {
+                dot_token,
+                method,
+                turbofish,
+                paren_token,
+                args,
+            };
+            ExprMark::MethodCall(mark)
+        } else if peek_macro(input) {
+            let path = input.call(syn::Path::parse_mod_style)?;
+            let bang_token = input.parse()?;
+            let (delimiter, tts) = if input.is_empty() {
+                let delimiter = syn::MacroDelimiter::Paren(syn::token::Paren::default());
+                (delimiter, proc_macro2::TokenStream::new())
+            } else {
+                syn::mac::parse_delimiter(input)?
+            };
+            let mac = crate::resyn::Macro {
+                path,
+                bang_token,
+                delimiter,
+                tts,
+            };
+            let mark = mark::Macro { mac };
+            ExprMark::Macro(mark)
+        } else if let Some(entry) = peek_sugar(input) {
+            let name: syn::Ident = input.parse()?;
+            if !cfg!(feature = "sugars") {
+                let msg = format!("unknown marker `{}`; enable the `sugars` feature", name);
+                return Err(syn::Error::new(name.span(), msg));
+            }
+            let (turbofish, args) = sugar_args(input, entry.args)?;
+            let mark = mark::Sugar {
+                name,
+                turbofish,
+                args,
+                lowering: entry.lowering,
+            };
+            ExprMark::Sugar(mark)
+        } else if input.peek(syn::Ident)
+            || input.peek(syn::Token![::])
+            || input.peek(syn::Token![<])
+            || input.peek(syn::Token![self])
+            || input.peek(syn::Token![Self])
+            || input.peek(syn::Token![super])
+            || input.peek(syn::Token![crate])
+        {
+            let func: ExprPath = input.parse()?;
+            let mark = mark::Call { func };
+            ExprMark::Call(mark)
+        } else if input.is_empty() {
+            return Err(input.error("expected a turboball marker"));
+        } else {
+            // spans the whole marker, rather than only its first token
+            let marker: proc_macro2::TokenStream = input.parse()?;
+            let msg = format!("unknown turboball marker `{}`", marker);
+            return Err(syn::Error::new_spanned(marker, msg));
+        };
+        Ok(mark)
+    }
+}
+
+// Parses `let pats =`, the binding part of a `let` or `if let` mark.
+#[cfg(feature = "full")]
+fn let_mark(input: syn::parse::ParseStream) -> syn::Result<mark::Let> {
+    let let_token = input.parse()?;
+    let pats = {
+        let mut pats = Punctuated::new();
+        input.parse::<Option<syn::Token![|]>>()?;
+        let value: syn::Pat = input.parse()?;
+        pats.push_value(value);
+        while input.peek(syn::Token![|])
+            && !input.peek(syn::Token![||])
+            && !input.peek(syn::Token![|=])
+        {
+            let punct = input.parse()?;
+            pats.push_punct(punct);
+            let value: syn::Pat = input.parse()?;
+            pats.push_value(value);
+        }
+        pats
+    };
+    let eq_token = input.parse()?;
+    Ok(mark::Let {
+        let_token,
+        pats,
+        eq_token,
+    })
+}
+
+// Parses the optional `let pats =` that follows an `if` or a `while`.
+#[cfg(feature = "full")]
+fn cond_let(input: syn::parse::ParseStream) -> syn::Result<Option<mark::Let>> {
+    if input.peek(syn::Token![let]) {
+        let_mark(input).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+// Parses the next operator if it's a compound assignment, such as `+=`.
+#[cfg(feature = "full")]
+fn assign_op(input: syn::parse::ParseStream) -> syn::Result<Option<syn::BinOp>> {
+    match input.fork().parse::<syn::BinOp>() {
+        Ok(ref op) if parsing::is_assign_op(op) => input.parse().map(Some),
+        _ => Ok(None),
+    }
+}
+
+// Whether the next tokens are a macro path and its bang, such as `dbg!`.
+#[cfg(feature = "full")]
+fn peek_macro(input: syn::parse::ParseStream) -> bool {
+    let ahead = input.fork();
+    ahead.call(syn::Path::parse_mod_style).is_ok()
+        && ahead.peek(syn::Token![!])
+        && !ahead.peek(syn::Token![!=])
+}
+
+// Whether the next token is the given contextual keyword, which syn parses
+// as an identifier.
+#[cfg(feature = "full")]
+fn peek_keyword(input: syn::parse::ParseStream, keyword: &str) -> bool {
+    input.peek(syn::Ident)
+        && input
+            .fork()
+            .parse::<syn::Ident>()
+            .map_or(false, |ident| ident == keyword)
+}
+
+// Looks up the sugar named by the next identifier, unless that identifier
+// starts a longer path.
+#[cfg(feature = "full")]
+fn peek_sugar(input: syn::parse::ParseStream) -> Option<&'static sugar::Entry> {
+    if !input.peek(syn::Ident) || input.peek2(syn::Token![::]) {
+        return None;
+    }
+    let name: syn::Ident = input.fork().parse().ok()?;
+    sugar::lookup(&name.to_string())
+}
+
+// Parses the arguments that follow a sugar marker's name. All but the last
+// argument are parsed without binary operators, so `::(fold 0 |a, b| a + b)`
+// is split into `0` and `|a, b| a + b`.
+#[cfg(feature = "full")]
+fn sugar_args(
+    input: syn::parse::ParseStream,
+    args: sugar::Args,
+) -> syn::Result<(Option<syn::Type>, Vec<Expr>)> {
+    let args = match args {
+        sugar::Args::Zero => (None, vec![]),
+        sugar::Args::One => (None, vec![input.parse()?]),
+        sugar::Args::Two => (
+            None,
+            vec![input.call(parsing::expr_leading_arg)?, input.parse()?],
+        ),
+        sugar::Args::Turbofish if input.is_empty() => (None, vec![]),
+        sugar::Args::Turbofish => (Some(input.parse()?), vec![]),
+    };
+    Ok(args)
+}
@@ -0,0 +1,180 @@
+use super::{Await, ExprMark, Let, Sugar};
+use crate::resyn::expr::turboball::sugar::Lowering;
+use crate::resyn::expr::{self, Expr};
+use syn::spanned::Spanned;
+
+#[cfg(feature = "printing")]
+impl quote::ToTokens for ExprMark {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            ExprMark::Box(mark_box) => mark_box.box_token.to_tokens(tokens),
+            ExprMark::Unary(mark_unary) => mark_unary.op.to_tokens(tokens),
+            ExprMark::Cast(mark_cast) => {
+                mark_cast.as_token.to_tokens(tokens);
+                mark_cast.ty.to_tokens(tokens);
+            }
+            ExprMark::Type(mark_type) => {
+                mark_type.colon_token.to_tokens(tokens);
+                mark_type.ty.to_tokens(tokens);
+            }
+            ExprMark::Let(mark_let) => mark_let.to_tokens(tokens),
+            ExprMark::If(mark_if) => {
+                mark_if.if_token.to_tokens(tokens);
+                mark_if.let_mark.to_tokens(tokens);
+            }
+            ExprMark::While(mark_while) => {
+                mark_while.label.to_tokens(tokens);
+                mark_while.while_token.to_tokens(tokens);
+                mark_while.let_mark.to_tokens(tokens);
+            }
+            ExprMark::ForLoop(mark_for_loop) => {
+                mark_for_loop.label.to_tokens(tokens);
+                mark_for_loop.for_token.to_tokens(tokens);
+                mark_for_loop.pat.to_tokens(tokens);
+                mark_for_loop.in_token.to_tokens(tokens);
+            }
+            ExprMark::Loop(mark_loop) => {
+                mark_loop.label.to_tokens(tokens);
+                mark_loop.loop_token.to_tokens(tokens);
+            }
+            ExprMark::Match(mark_match) => mark_match.match_token.to_tokens(tokens),
+            ExprMark::Unsafe(mark_unsafe) => mark_unsafe.unsafe_token.to_tokens(tokens),
+            ExprMark::Block(mark_block) => mark_block.label.to_tokens(tokens),
+            ExprMark::Assign(mark_assign) => {
+                mark_assign.eq_token.to_tokens(tokens);
+                mark_assign.right.to_tokens(tokens);
+            }
+            ExprMark::AssignOp(mark_assign_op) => {
+                mark_assign_op.op.to_tokens(tokens);
+                mark_assign_op.right.to_tokens(tokens);
+            }
+            ExprMark::Reference(mark_reference) => {
+                mark_reference.and_token.to_tokens(tokens);
+                mark_reference.mutability.to_tokens(tokens);
+            }
+            ExprMark::Break(mark_break) => {
+                mark_break.break_token.to_tokens(tokens);
+                mark_break.label.to_tokens(tokens);
+            }
+            ExprMark::Continue(mark_continue) => {
+                mark_continue.continue_token.to_tokens(tokens);
+                mark_continue.label.to_tokens(tokens);
+            }
+            ExprMark::Return(mark_return) => mark_return.return_token.to_tokens(tokens),
+            ExprMark::Macro(mark_macro) => mark_macro.mac.to_tokens(tokens),
+            ExprMark::Paren(mark_paren) => mark_paren.paren_token.to_tokens(tokens),
+            ExprMark::Group(mark_group) => mark_group.group_token.to_tokens(tokens),
+            ExprMark::Async(mark_async) => {
+                mark_async.async_token.to_tokens(tokens);
+                mark_async.capture.to_tokens(tokens);
+            }
+            ExprMark::TryBlock(mark_try_block) => mark_try_block.try_token.to_tokens(tokens),
+            ExprMark::Try(mark_try) => mark_try.question_token.to_tokens(tokens),
+            ExprMark::Yield(mark_yield) => mark_yield.yield_token.to_tokens(tokens),
+            ExprMark::Await(mark_await) => mark_await.await_token.to_tokens(tokens),
+            ExprMark::Call(mark_call) => mark_call.func.to_tokens(tokens),
+            ExprMark::MethodCall(mark_method_call) => {
+                mark_method_call.dot_token.to_tokens(tokens);
+                mark_method_call.method.to_tokens(tokens);
+                mark_method_call.turbofish.to_tokens(tokens);
+                mark_method_call.paren_token.surround(tokens, |tokens| {
+                    mark_method_call.args.to_tokens(tokens);
+                });
+            }
+            ExprMark::Sugar(mark_sugar) => {
+                mark_sugar.name.to_tokens(tokens);
+                mark_sugar.turbofish.to_tokens(tokens);
+                for arg in &mark_sugar.args {
+                    arg.to_tokens(tokens);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "printing")]
+impl quote::ToTokens for Let {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.let_token.to_tokens(tokens);
+        self.pats.to_tokens(tokens);
+        self.eq_token.to_tokens(tokens);
+    }
+}
+
+#[cfg(feature = "printing")]
+impl Sugar {
+    /// Lowers the sugar applied on the `receiver`, eg.
+    /// `iter::(fold 0 f)` into `iter.fold(0, f)`.
+    ///
+    /// The generated tokens carry the receiver's span, so that errors on
+    /// the lowered call point at the receiver.
+    pub fn lower(&self, receiver: &Expr) -> proc_macro2::TokenStream {
+        let span = receiver.span();
+        let mut wrapped = proc_macro2::TokenStream::new();
+        expr::printing::wrap_receiver(&mut wrapped, receiver);
+        let name = &self.name;
+        let turbofish = self
+            .turbofish
+            .as_ref()
+            .map(|ty| quote::quote_spanned!(span=> ::<#ty>));
+        let args = &self.args;
+        match self.lowering {
+            Lowering::Method => {
+                quote::quote_spanned!(span=> #wrapped.#name#turbofish(#(#args),*))
+            }
+            Lowering::Mutating => quote::quote_spanned!(span=> {
+                let mut __v = #receiver;
+                __v.#name#turbofish(#(#args),*);
+                __v
+            }),
+            Lowering::Associated(path) => {
+                let path = path.iter().map(|segment| syn::Ident::new(segment, span));
+                quote::quote_spanned!(span=> ::#(#path)::*::#name#turbofish(#receiver, #(#args),*))
+            }
+            Lowering::Default => {
+                let default = match self.turbofish {
+                    Some(ref ty) => {
+                        quote::quote_spanned!(span=> <#ty as ::std::default::Default>::default())
+                    }
+                    None => quote::quote_spanned!(span=> ::std::default::Default::default()),
+                };
+                quote::quote_spanned!(span=> {
+                    let () = #receiver;
+                    #default
+                })
+            }
+            Lowering::Unique => quote::quote_spanned!(span=> {
+                let mut __seen = ::std::collections::HashSet::new();
+                #wrapped.filter(move |__item| __seen.insert(::std::clone::Clone::clone(__item)))
+            }),
+            Lowering::MapKeys => {
+                let f = &self.args[0];
+                quote::quote_spanned!(span=> #wrapped.map({
+                    #[allow(unused_mut)]
+                    let mut __f = #f;
+                    move |(__k, __v)| (__f(__k), __v)
+                }))
+            }
+            Lowering::MapValues => {
+                let f = &self.args[0];
+                quote::quote_spanned!(span=> #wrapped.map({
+                    #[allow(unused_mut)]
+                    let mut __f = #f;
+                    move |(__k, __v)| (__k, __f(__v))
+                }))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "printing")]
+impl Await {
+    /// Lowers the await applied on the `receiver`, ie. `fut::(await)` into
+    /// `fut.await`, which `syn` can't represent as an expression.
+    pub fn lower(&self, receiver: &Expr) -> proc_macro2::TokenStream {
+        let mut wrapped = proc_macro2::TokenStream::new();
+        expr::printing::wrap_receiver(&mut wrapped, receiver);
+        let await_token = &self.await_token;
+        quote::quote!(#wrapped.#await_token)
+    }
+}
@@ -0,0 +1,485 @@
+//! Registry of the sugar markers, which are method calls written as
+//! turboballs, eg. `iter::(fold 0 |acc, x| acc + x)`.
+//!
+//! They are only accepted when the `sugars` feature is enabled.
+
+/// The arguments that a sugar marker takes after its name.
+#[derive(Clone, Copy)]
+pub enum Args {
+    /// No arguments: `::(iter)`.
+    Zero,
+    /// A single expression: `::(get 0)`.
+    ///
+    /// Closures and function paths are both expressions, so
+    /// `::(map |x| x * 2)` and `::(map double)` are both accepted.
+    One,
+    /// Two expressions: `::(fold 0 |acc, x| acc + x)`.
+    ///
+    /// The first one can't have binary operators, since those would
+    /// otherwise swallow the second one. If it's a closure, neither can its
+    /// body: `::(map_or_else |e| 0 |x| x * 2)`.
+    Two,
+    /// An optional type, turbofished into the call:
+    /// `::(collect Vec<u8>)`.
+    Turbofish,
+}
+
+/// How a sugar marker is lowered around its receiver.
+#[derive(Clone, Copy)]
+pub enum Lowering {
+    /// `receiver.name(args..)`.
+    Method,
+    /// `{ let mut __v = receiver; __v.name(args..); __v }`, for methods that
+    /// mutate their receiver in place, such as `Vec::dedup`.
+    Mutating,
+    /// `::path::name(receiver, args..)`, for associated functions that
+    /// aren't methods, such as `Box::leak`.
+    Associated(&'static [&'static str]),
+    /// `Default::default()`, or `<ty>::default()` if a type is given. The
+    /// receiver must be `()`, eg. `()::(default Vec<u8>)`.
+    Default,
+    /// Yields only the first occurrence of each item of the receiver
+    /// iterator, whose items must be `Clone + Hash + Eq`.
+    Unique,
+    /// `receiver.map(|(k, v)| (f(k), v))`, for iterators over pairs.
+    MapKeys,
+    /// `receiver.map(|(k, v)| (k, f(v)))`, for iterators over pairs.
+    MapValues,
+}
+
+pub struct Entry {
+    pub name: &'static str,
+    pub args: Args,
+    pub lowering: Lowering,
+}
+
+pub static REGISTRY: &[Entry] = &[
+    // constructors
+    Entry {
+        name: "default",
+        args: Args::Turbofish,
+        lowering: Lowering::Default,
+    },
+    // conversions
+    Entry {
+        name: "into",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    // iterators
+    Entry {
+        name: "iter",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "fold",
+        args: Args::Two,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "count",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "sum",
+        args: Args::Turbofish,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "reduce",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "collect",
+        args: Args::Turbofish,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "map",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "filter_map",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "flat_map",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "map_while",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "take",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "peekable",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "fuse",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "cycle",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "step_by",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "scan",
+        args: Args::Two,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "nth",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "position",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "find",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "any",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "all",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "map_keys",
+        args: Args::One,
+        lowering: Lowering::MapKeys,
+    },
+    Entry {
+        name: "map_values",
+        args: Args::One,
+        lowering: Lowering::MapValues,
+    },
+    Entry {
+        name: "unique",
+        args: Args::Zero,
+        lowering: Lowering::Unique,
+    },
+    // characters
+    Entry {
+        name: "to_digit",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "is_alphanumeric",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    // strings
+    Entry {
+        name: "parse",
+        args: Args::Turbofish,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "lines",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "split",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "trim",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "chars",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "bytes",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "to_uppercase",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "to_lowercase",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "starts_with",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "ends_with",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    // slices
+    Entry {
+        name: "first",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "last",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "get",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "get_mut",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "contains",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "join",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "windows",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "chunks",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "split_at",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "split_first",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "split_last",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "as_ptr",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "as_mut_ptr",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "rotate_left",
+        args: Args::One,
+        lowering: Lowering::Mutating,
+    },
+    Entry {
+        name: "rotate_right",
+        args: Args::One,
+        lowering: Lowering::Mutating,
+    },
+    Entry {
+        name: "to_vec",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    // vectors
+    Entry {
+        name: "dedup",
+        args: Args::Zero,
+        lowering: Lowering::Mutating,
+    },
+    Entry {
+        name: "dedup_by_key",
+        args: Args::One,
+        lowering: Lowering::Mutating,
+    },
+    Entry {
+        name: "retain",
+        args: Args::One,
+        lowering: Lowering::Mutating,
+    },
+    Entry {
+        name: "extend",
+        args: Args::One,
+        lowering: Lowering::Mutating,
+    },
+    // maps
+    Entry {
+        name: "insert",
+        args: Args::Two,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "remove",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "or_insert",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "or_insert_with",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "or_default",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    // allocations
+    Entry {
+        name: "into_boxed_slice",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "leak",
+        args: Args::Zero,
+        lowering: Lowering::Associated(&["std", "boxed", "Box"]),
+    },
+    // numbers
+    Entry {
+        name: "abs",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "pow",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "sqrt",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "wrapping_add",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "checked_add",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "saturating_add",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    // orderings
+    Entry {
+        name: "cmp",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "partial_cmp",
+        args: Args::One,
+        lowering: Lowering::Method,
+    },
+    // options
+    Entry {
+        name: "as_deref",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "cloned",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "map_or",
+        args: Args::Two,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "map_or_else",
+        args: Args::Two,
+        lowering: Lowering::Method,
+    },
+    // results
+    Entry {
+        name: "unwrap",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "unwrap_err",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    // interior mutability
+    Entry {
+        name: "lock",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "borrow",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+    Entry {
+        name: "borrow_mut",
+        args: Args::Zero,
+        lowering: Lowering::Method,
+    },
+];
+
+pub fn lookup(name: &str) -> Option<&'static Entry> {
+    REGISTRY.iter().find(|entry| entry.name == name)
+}
@@ -5,27 +5,80 @@
 extern crate proc_macro;
 extern crate proc_macro2;
 
-mod resyn;
 use proc_macro::TokenStream;
-use quote::quote;
+use proc_macro2::{Delimiter, Spacing, TokenTree};
 
 /// Changes the `Block` parsing syntax so that the `::()` postfix
 /// serves as a general postfix operator.
+///
+/// The rejected inputs are collected in `tests/ui/`. The parser itself is
+/// the `sonic_spin_core` crate, which other proc-macros can call.
+#[cfg_attr(
+    not(feature = "sugars"),
+    doc = r#"
+Sugar markers are rejected unless the `sugars` feature is enabled:
+
+```compile_fail
+#![feature(proc_macro_hygiene)]
+use sonic_spin::sonic_spin;
+
+sonic_spin! {
+    let res = Some(1)::(unwrap);
+}
+```
+"#
+)]
 #[proc_macro]
 pub fn sonic_spin(item: TokenStream) -> TokenStream {
-    let rebraced = {
-        use std::str::FromStr;
-        let rebraced: String = String::from("{") + &item.to_string() + &"}";
-        TokenStream::from_str(&rebraced).unwrap()
-    };
+    match sonic_spin_core::spin(item.into()) {
+        Ok(reparsed) => {
+            trace(&reparsed);
+            reparsed.into()
+        }
+        Err(err) => err.to_compile_error().into(),
+    }
+}
 
-    let input = syn::parse_macro_input!(rebraced as resyn::expr::Block);
-    let reparsed = quote! {
-       #input
-    };
+// Prints an expansion to stderr, under the `trace-expansion` feature.
+fn trace(expansion: &proc_macro2::TokenStream) {
+    if cfg!(feature = "trace-expansion") {
+        let mut out = String::new();
+        pretty(&mut out, expansion.clone(), 0);
+        eprintln!("{}", out.trim_end());
+    }
+}
 
-    // let quoted = format!(" ==> <  {}  >\n", &reparsed);
-    // println!("{}", &quoted);
+// Writes the tokens with a statement per line, indenting the braced groups.
+fn pretty(out: &mut String, tokens: proc_macro2::TokenStream, depth: usize) {
+    for tt in tokens {
+        match tt {
+            TokenTree::Group(ref group) if group.delimiter() == Delimiter::Brace => {
+                out.push('{');
+                newline(out, depth + 1);
+                pretty(out, group.stream(), depth + 1);
+                newline(out, depth);
+                out.push_str("} ");
+            }
+            TokenTree::Punct(ref punct) if punct.as_char() == ';' => {
+                out.push(';');
+                newline(out, depth);
+            }
+            TokenTree::Punct(ref punct) if punct.spacing() == Spacing::Joint => {
+                out.push(punct.as_char());
+            }
+            tt => {
+                out.push_str(&tt.to_string());
+                out.push(' ');
+            }
+        }
+    }
+}
 
-    reparsed.into()
+// Starts a line at the given depth, dropping the trailing whitespace (and
+// so any empty line) before it.
+fn newline(out: &mut String, depth: usize) {
+    let len = out.trim_end().len();
+    out.truncate(len);
+    out.push('\n');
+    out.push_str(&"    ".repeat(depth));
 }
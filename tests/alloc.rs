@@ -0,0 +1,32 @@
+#![cfg(feature = "sugars")]
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn alloc_into_boxed_slice() {
+    sonic_spin! {
+        let alt: Box<[u32]> = vec![1, 2].into_boxed_slice();
+
+        let res: Box<[u32]> = vec![1, 2]::(into_boxed_slice);
+
+        assert_eq!(&*res, &[1, 2]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn alloc_leak() {
+    sonic_spin! {
+        let alt: &'static mut u32 = Box::leak(Box::new(3));
+
+        let res: &'static mut u32 = Box::new(3)::(leak);
+        *res += 1;
+
+        assert_eq!(*res, 4);
+        assert_eq!(*alt + 1, *res);
+    }
+}
@@ -0,0 +1,45 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+#![feature(box_syntax)]
+#![feature(type_ascription)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn ascription_normal() {
+    sonic_spin! {
+        let alt = (vec![]: Vec<u8>);
+
+        let res = vec![]::(: Vec<u8>);
+
+        assert_eq!(res, Vec::<u8>::new());
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn ascription_cast() {
+    sonic_spin! {
+        let alt = (1: u8) as u32;
+
+        let res = 1::(: u8)::(as u32);
+
+        assert_eq!(res, 1u32);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn ascription_box_operand() {
+    sonic_spin! {
+        let compute = || 5;
+        let alt = (box compute(): Box<i32>);
+
+        let res = (compute()::(box))::(: Box<i32>);
+
+        assert_eq!(res, box 5);
+        assert_eq!(res, alt);
+    }
+}
@@ -0,0 +1,83 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+#![feature(box_syntax)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn assign_rhs() {
+    sonic_spin! {
+        let alt;
+        alt = box 5;
+
+        let res;
+        res = 5::(box);
+
+        assert_eq!(res, box 5);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn assign_op_rhs() {
+    sonic_spin! {
+        let x = &2;
+        let mut alt = 1;
+        alt += *x;
+
+        let mut res = 1;
+        res += x::(*);
+
+        assert_eq!(res, 3);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn assign_mark() {
+    sonic_spin! {
+        let alt;
+        alt = 5;
+
+        let res;
+        res::(= 5);
+
+        assert_eq!(res, 5);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn assign_mark_index() {
+    sonic_spin! {
+        let i = 1;
+        let mut alt = [1, 2, 3];
+        alt[i] = 0;
+
+        let mut res = [1, 2, 3];
+        res[i]::(= 0);
+
+        assert_eq!(res, [1, 0, 3]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn assign_op_mark() {
+    sonic_spin! {
+        let mut alt = 1;
+        alt += 1;
+        alt <<= 2;
+        alt &= 12;
+
+        let mut res = 1;
+        res::(+= 1);
+        res::(<<= 2);
+        res::(&= 12);
+
+        assert_eq!(res, 8);
+        assert_eq!(res, alt);
+    }
+}
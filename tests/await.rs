@@ -0,0 +1,53 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+#![feature(async_await)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+use std::future::Future;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+// Polls the future until it's ready, without ever being woken up.
+fn block_on<F: Future>(fut: F) -> F::Output {
+    fn raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker {
+            raw_waker()
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        RawWaker::new(std::ptr::null(), &VTABLE)
+    }
+    let waker = unsafe { Waker::from_raw(raw_waker()) };
+    let mut cx = Context::from_waker(&waker);
+    let mut fut = Box::pin(fut);
+    loop {
+        if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn await_normal() {
+    sonic_spin! {
+        let alt = block_on(async { async { 8 }.await });
+        let res = block_on(async { async { 8 }::(await) });
+
+        assert_eq!(res, 8);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn await_try() {
+    sonic_spin! {
+        let fetch = || async { Ok::<u32, ()>(8) };
+
+        let alt = block_on(async { Ok::<u32, ()>(fetch().await? + 1) });
+        let res = block_on({ Ok::<u32, ()>(fetch()::(await)::(?) + 1) }::(async));
+
+        assert_eq!(res, Ok(9));
+        assert_eq!(res, alt);
+    }
+}
@@ -23,3 +23,25 @@ fn block_label() {
         assert_eq!(res, alt);
     }
 }
+
+#[test]
+fn block_label_break() {
+    sonic_spin! {
+        let alt = 'alt_label: {
+            if true {
+                break 'alt_label 1;
+            };
+            2
+        };
+
+        let res = {
+            true::(if) {
+                1::(break 'res_label);
+            };
+            2
+        }::('res_label:);
+
+        assert_eq!(res, 1);
+        assert_eq!(res, alt);
+    }
+}
@@ -29,3 +29,153 @@ fn box_let() {
         assert_eq!(alt, res);
     }
 }
+
+#[test]
+fn box_chain() {
+    sonic_spin! {
+        struct Inner {
+            c: u32,
+        }
+        struct Outer {
+            a: Middle,
+        }
+        struct Middle;
+        impl Middle {
+            fn b(&self) -> Inner {
+                Inner { c: 3 }
+            }
+        }
+        let obj = Outer { a: Middle };
+
+        let alt = box obj.a.b().c;
+
+        let res = obj.a.b().c::(box);
+
+        assert_eq!(res, box 3);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn box_precedence() {
+    sonic_spin! {
+        let x = 2;
+        let alt: Box<i32> = box x;
+
+        let res = &x::(box);
+
+        assert_eq!(*res, alt);
+
+        let res: Box<&i32> = (&x)::(box);
+
+        assert_eq!(res, box &2);
+    }
+}
+
+#[test]
+fn box_if() {
+    sonic_spin! {
+        let alt = box (if true { 1 } else { 2 });
+
+        let res = true::(if) { 1 } else { 2 }::(box);
+
+        assert_eq!(res, box 1);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn box_comments() {
+    sonic_spin! {
+        let alt = box 1;
+
+        let res = 1::(/* box it */ box);
+
+        assert_eq!(res, alt);
+
+        let res = 1 /* before */ :: /* between */ ( // leading
+            box // trailing
+        ) /* after */;
+
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn box_negative() {
+    sonic_spin! {
+        let alt = box (-5);
+
+        let res = (-5)::(box);
+
+        assert_eq!(res, box -5);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn box_tail() {
+    let alt = {
+        let x = 1;
+        box x
+    };
+
+    let res = sonic_spin! {
+        let x = 1;
+        x::(box)
+    };
+
+    assert_eq!(res, box 1);
+    assert_eq!(res, alt);
+}
+
+#[test]
+fn box_turbofish_receiver() {
+    sonic_spin! {
+        let alt = box (0..3).collect::<Vec<_>>();
+
+        let res = (0..3).collect::<Vec<_>>()::(box);
+
+        assert_eq!(res, box vec![0, 1, 2]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn box_index_receiver() {
+    sonic_spin! {
+        let grid = vec![vec![1, 2], vec![3, 4]];
+        let (x, y) = (0, 1);
+        let alt = box grid[y][x];
+
+        let res = grid[y][x]::(box);
+
+        assert_eq!(res, box 3);
+        assert_eq!(res, alt);
+
+        let alt = box &grid[y];
+
+        let res = grid[y]::(&)::(box);
+
+        assert_eq!(res, box &vec![3, 4]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn box_literal_receiver() {
+    sonic_spin! {
+        let alt = box r#"a "quoted" str"#;
+
+        let res = r#"a "quoted" str"#::(box);
+
+        assert_eq!(res, box "a \"quoted\" str");
+        assert_eq!(res, alt);
+
+        let alt = box 1_000u64;
+
+        let res = 1_000u64::(box);
+
+        assert_eq!(res, alt);
+    }
+}
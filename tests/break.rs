@@ -40,3 +40,23 @@ fn break_labeled() {
         assert_eq!(res, alt);
     }
 }
+
+#[test]
+fn break_in_if() {
+    sonic_spin! {
+        let alt = loop {
+            if true {
+                break 5;
+            };
+        };
+
+        let res = loop {
+            true::(if) {
+                5::(break);
+            };
+        };
+
+        assert_eq!(res, 5);
+        assert_eq!(res, alt);
+    }
+}
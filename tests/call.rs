@@ -0,0 +1,94 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn call_tuple_struct() {
+    sonic_spin! {
+        #[derive(Debug, PartialEq)]
+        struct Wrapper(i32);
+
+        let alt = Wrapper(5);
+
+        let res = 5::(Wrapper);
+
+        assert_eq!(res, Wrapper(5));
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn call_variant() {
+    sonic_spin! {
+        let alt = Some(5);
+
+        let res = 5::(Some);
+
+        assert_eq!(res, Some(5));
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn call_path() {
+    sonic_spin! {
+        let alt = String::from("spin");
+
+        let res = "spin"::(String::from);
+
+        assert_eq!(res, "spin");
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn call_turboball_args() {
+    sonic_spin! {
+        let process = |a: u32, b: u32| a * 10 + b;
+        let cond = true;
+        let x = 2;
+        let alt = process(if cond { 1 } else { 2 }, match x { 2 => 3, _ => 4 });
+
+        let res = process(cond::(if) { 1 } else { 2 }, x::(match) { 2 => 3, _ => 4 });
+
+        assert_eq!(res, 13);
+        assert_eq!(res, alt);
+
+        let res = process(x::(match) { 2 => 3, _ => 4 }, (!cond)::(if) { 1 } else { 2 });
+
+        assert_eq!(res, 32);
+    }
+}
+
+fn first(x: i32) -> i32 {
+    x + 1
+}
+
+#[test]
+fn call_sugar_name_through_path() {
+    sonic_spin! {
+        let alt = first(1);
+
+        let res = 1::(self::first);
+
+        assert_eq!(res, 2);
+        assert_eq!(res, alt);
+    }
+}
+
+#[cfg(feature = "sugars")]
+#[test]
+fn call_sugar_name_is_sugar() {
+    sonic_spin! {
+        let v = vec![5, 6];
+        let alt = v.first();
+
+        let res = v::(first);
+
+        assert_eq!(res, Some(&5));
+        assert_eq!(res, alt);
+    }
+}
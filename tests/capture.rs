@@ -0,0 +1,76 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn capture_bodies() {
+    sonic_spin! {
+        let mut alt = 0;
+        if true {
+            alt += 1;
+        };
+        while alt < 3 {
+            alt += 1;
+        };
+        for x in 0..3 {
+            alt += x;
+        };
+        match alt {
+            _ => alt += 1,
+        };
+        loop {
+            alt += 1;
+            break;
+        };
+
+        let mut acc = 0;
+        true::(if) {
+            acc += 1;
+        };
+        (acc < 3)::(while) {
+            acc += 1;
+        };
+        (0..3)::(for x in) {
+            acc += x;
+        };
+        acc::(match) {
+            _ => acc += 1,
+        };
+        {
+            acc += 1;
+            break;
+        }::(loop);
+
+        assert_eq!(acc, 8);
+        assert_eq!(acc, alt);
+    }
+}
+
+#[test]
+fn capture_moved() {
+    sonic_spin! {
+        let alt = {
+            let s = String::from("a");
+            let mut out = Vec::new();
+            if true {
+                out.push(s);
+            };
+            out
+        };
+
+        let res = {
+            let s = String::from("a");
+            let mut out = Vec::new();
+            true::(if) {
+                out.push(s);
+            };
+            out
+        };
+
+        assert_eq!(res, vec!["a".to_string()]);
+        assert_eq!(res, alt);
+    }
+}
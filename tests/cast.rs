@@ -0,0 +1,67 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn cast_normal() {
+    sonic_spin! {
+        let x = 3u8;
+        let alt = x as f64;
+
+        let res = x::(as f64);
+
+        assert_eq!(res, 3.0);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn cast_chain() {
+    sonic_spin! {
+        let alt = 300i32 as u8 as u16;
+
+        let res = 300i32::(as u8)::(as u16);
+
+        assert_eq!(res, 44);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn cast_precedence() {
+    sonic_spin! {
+        let x = 1i32;
+        let alt = -(x as f64);
+
+        let res = x::(as f64)::(-);
+
+        assert_eq!(res, -1.0);
+        assert_eq!(res, alt);
+
+        let alt = (x as u8).count_ones();
+
+        let res = x::(as u8)::(.count_ones());
+
+        assert_eq!(res, 1);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn cast_trait_object() {
+    use std::fmt::Debug;
+
+    sonic_spin! {
+        let x = 5;
+        let alt = format!("{:?}", &x as &dyn Debug);
+
+        let d = (&x)::(as &dyn Debug);
+        let res = format!("{:?}", d);
+
+        assert_eq!(res, "5");
+        assert_eq!(res, alt);
+    }
+}
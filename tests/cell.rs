@@ -0,0 +1,50 @@
+#![cfg(feature = "sugars")]
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+use std::cell::RefCell;
+use std::sync::Mutex;
+
+#[test]
+fn cell_borrow() {
+    sonic_spin! {
+        let cell = RefCell::new(3);
+        let alt = *cell.borrow();
+
+        let res = *cell::(borrow);
+
+        assert_eq!(res, 3);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn cell_borrow_mut() {
+    sonic_spin! {
+        let alt = RefCell::new(3);
+        *alt.borrow_mut() += 1;
+
+        let res = RefCell::new(3);
+        *res::(borrow_mut) += 1;
+
+        assert_eq!(res.into_inner(), 4);
+        assert_eq!(alt.into_inner(), 4);
+    }
+}
+
+#[test]
+fn cell_lock() {
+    sonic_spin! {
+        let alt = Mutex::new(3);
+        *alt.lock().unwrap() += 1;
+
+        let res = Mutex::new(3);
+        *res::(lock).unwrap() += 1;
+
+        assert_eq!(res.into_inner().unwrap(), 4);
+        assert_eq!(alt.into_inner().unwrap(), 4);
+    }
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "sugars")]
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn char_to_digit() {
+    sonic_spin! {
+        let alt = '7'.to_digit(10);
+
+        let res = '7'::(to_digit 10);
+
+        assert_eq!(res, Some(7));
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn char_is_alphanumeric() {
+    sonic_spin! {
+        let alt = '_'.is_alphanumeric();
+
+        let res = '_'::(is_alphanumeric);
+
+        assert_eq!(res, false);
+        assert_eq!(res, alt);
+    }
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "sugars")]
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+use std::cmp::Ordering;
+
+#[test]
+fn cmp_match() {
+    sonic_spin! {
+        let (a, b) = (1i32, 2i32);
+
+        let alt = match a.cmp(&b) {
+            Ordering::Less => "less",
+            Ordering::Equal => "equal",
+            Ordering::Greater => "greater",
+        };
+
+        let res = a::(cmp &b)::(match) {
+            Ordering::Less => "less",
+            Ordering::Equal => "equal",
+            Ordering::Greater => "greater",
+        };
+
+        assert_eq!(res, "less");
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn cmp_partial() {
+    sonic_spin! {
+        let alt = 2.0f64.partial_cmp(&1.0);
+
+        let res = 2.0f64::(partial_cmp &1.0);
+
+        assert_eq!(res, Some(Ordering::Greater));
+        assert_eq!(res, alt);
+    }
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "sugars")]
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn default_inferred() {
+    sonic_spin! {
+        let alt: i32 = Default::default();
+
+        let res: i32 = ()::(default);
+
+        assert_eq!(res, 0);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn default_typed() {
+    sonic_spin! {
+        let alt = <Vec<u8>>::default();
+
+        let res = ()::(default Vec<u8>);
+
+        assert_eq!(res, Vec::<u8>::new());
+        assert_eq!(res, alt);
+    }
+}
@@ -72,3 +72,47 @@ fn for_nested_pattern_labeled() {
         assert_eq!(acc, _acc);
     }
 }
+
+#[test]
+fn for_triple_nested_labeled() {
+    sonic_spin! {
+        let mut _acc = 0;
+        'outermost_: for x in 0..4 {
+            'middle_: for y in 0..4 {
+                'innermost_: for z in 0..4 {
+                    if x == 2 && y == 2 && z == 2 {
+                        break 'outermost_;
+                    }
+                    if z == 0 {
+                        continue 'innermost_;
+                    }
+                    if z > y {
+                        continue 'middle_;
+                    }
+                    _acc += x * 100 + y * 10 + z;
+                }
+            }
+        };
+
+        let mut acc = 0;
+        (0..4)::('outermost: for x in) {
+            (0..4)::('middle: for y in) {
+                (0..4)::('innermost: for z in) {
+                    (x == 2 && y == 2 && z == 2)::(if) {
+                        break 'outermost;
+                    };
+                    (z == 0)::(if) {
+                        continue 'innermost;
+                    };
+                    (z > y)::(if) {
+                        continue 'middle;
+                    };
+                    acc += x * 100 + y * 10 + z;
+                }
+            }
+        };
+
+        assert_eq!(acc, 1332);
+        assert_eq!(acc, _acc);
+    }
+}
@@ -0,0 +1,43 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+#![feature(box_syntax)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+macro_rules! spin_tt {
+    ($receiver:expr, $($mark:tt)*) => {
+        sonic_spin! {
+            $receiver::($($mark)*)
+        }
+    };
+}
+
+macro_rules! spin_path {
+    ($receiver:expr, $func:path) => {
+        sonic_spin! {
+            $receiver::($func)
+        }
+    };
+}
+
+#[test]
+fn fragment_tt() {
+    let alt = box 5;
+
+    let res = spin_tt!(5, box);
+
+    assert_eq!(res, Box::new(5));
+    assert_eq!(res, alt);
+}
+
+#[test]
+fn fragment_path() {
+    let alt = Some(5);
+
+    let res = spin_path!(5, Option::Some);
+
+    assert_eq!(res, Some(5));
+    assert_eq!(res, alt);
+}
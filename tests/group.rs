@@ -0,0 +1,50 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use sonic_spin::sonic_spin;
+
+#[test]
+fn group_normal() {
+    sonic_spin! {
+        let x = 3;
+        let alt = x;
+
+        let res = x::(group);
+
+        assert_eq!(res, 3);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn group_precedence() {
+    sonic_spin! {
+        let (a, b, c) = (1i32, 2, 3);
+        let alt = (a + b) * c;
+
+        let res = (a + b)::(group) * c;
+
+        assert_eq!(res, 9);
+        assert_eq!(res, alt);
+
+        let res = a::(-)::(group)::(.pow(2));
+
+        assert_eq!(res, 1);
+    }
+}
+
+#[test]
+fn group_tokens() {
+    let res = sonic_spin_core::spin("-x::(as i32)::(group)".parse().unwrap()).unwrap();
+    let cast = Group::new(Delimiter::None, "x as i32".parse().unwrap());
+    let minus: TokenStream = "-".parse().unwrap();
+    let alt: TokenStream = minus.into_iter().chain(Some(TokenTree::Group(cast))).collect();
+    let alt: TokenStream = Some(TokenTree::Group(Group::new(Delimiter::Brace, alt)))
+        .into_iter()
+        .collect();
+
+    assert_eq!(res.to_string(), alt.to_string());
+}
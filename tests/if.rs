@@ -1,5 +1,6 @@
 #![feature(proc_macro_hygiene)]
 #![allow(unused_parens)]
+#![feature(box_syntax)]
 
 mod common;
 
@@ -175,3 +176,103 @@ fn if_nested() {
         assert_eq!(acc, alt);
     }
 }
+
+#[test]
+fn if_let_body() {
+    sonic_spin! {
+        let unbox = |x: Box<u32>| *x;
+
+        let alt = if true {
+            let x = box 3;
+            unbox(x)
+        } else {
+            0
+        };
+
+        let res = true::(if) {
+            let x = 3::(box);
+            unbox(x)
+        } else {
+            0
+        };
+
+        assert_eq!(res, 3);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn if_compound_condition() {
+    sonic_spin! {
+        let (a, b, c, d) = (1, 2, 4, 3);
+
+        let alt = if ((a < b) && (c > d)) {
+            3
+        } else {
+            4
+        };
+
+        let res = ((a < b) && (c > d))::(if) {
+            3
+        } else {
+            4
+        };
+
+        assert_eq!(res, 3);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn if_turboball_condition() {
+    sonic_spin! {
+        let (a, b) = (1, 1);
+
+        let alt = if (box a == box b) && (-a < 0) {
+            3
+        } else {
+            4
+        };
+
+        let res = ((a::(box) == b::(box)) && (a::(-) < 0))::(if) {
+            3
+        } else {
+            4
+        };
+
+        assert_eq!(res, 3);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn if_let() {
+    sonic_spin! {
+        let opt = Some(3);
+        let alt = if let Some(x) = opt { x + 1 } else { 0 };
+
+        let res = opt::(if let Some(x) =) { x + 1 } else { 0 };
+
+        assert_eq!(res, 4);
+        assert_eq!(res, alt);
+
+        let none: Option<u32> = None;
+
+        let res = none::(if let Some(x) =) { x + 1 } else { 0 };
+
+        assert_eq!(res, 0);
+    }
+}
+
+#[test]
+fn if_let_pats() {
+    sonic_spin! {
+        let opt = Some(0);
+        let alt = if let Some(0) | None = opt { 1 } else { 2 };
+
+        let res = opt::(if let Some(0) | None =) { 1 } else { 2 };
+
+        assert_eq!(res, 1);
+        assert_eq!(res, alt);
+    }
+}
@@ -0,0 +1,297 @@
+#![cfg(feature = "sugars")]
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+#![feature(box_syntax)]
+#![feature(iter_map_while)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn iter_fold() {
+    sonic_spin! {
+        let alt = (1..=4).fold(0, |a, b| a + b);
+
+        let res = (1..=4)::(fold 0 |a, b| a + b);
+
+        assert_eq!(res, 10);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_unique() {
+    sonic_spin! {
+        let res = vec![3, 1, 3, 2, 1].into_iter()::(unique).collect::<Vec<_>>();
+
+        assert_eq!(res, vec![3, 1, 2]);
+    }
+}
+
+#[test]
+fn iter_map_closure() {
+    sonic_spin! {
+        let alt = (1..4).map(|x| x * 2).collect::<Vec<_>>();
+
+        let res = (1..4)::(map |x| x * 2).collect::<Vec<_>>();
+
+        assert_eq!(res, vec![2, 4, 6]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_map_path() {
+    sonic_spin! {
+        fn double(x: u32) -> u32 {
+            x * 2
+        }
+
+        let alt = (1..4).map(double).collect::<Vec<_>>();
+
+        let res = (1..4)::(map double).collect::<Vec<_>>();
+
+        assert_eq!(res, vec![2, 4, 6]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_collect() {
+    sonic_spin! {
+        let alt: Vec<u8> = (1..4).collect();
+
+        let res: Vec<u8> = (1..4)::(collect);
+
+        assert_eq!(res, vec![1, 2, 3]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_collect_turbofish() {
+    sonic_spin! {
+        let alt = (1..4).collect::<Vec<u8>>();
+
+        let res = (1..4)::(collect Vec<u8>);
+
+        assert_eq!(res, vec![1, 2, 3]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_filter_map() {
+    sonic_spin! {
+        let alt = ["1", "a", "2"].iter().filter_map(|s| s.parse::<i32>().ok()).collect::<Vec<_>>();
+
+        let res = ["1", "a", "2"].iter()::(filter_map |s| s.parse::<i32>().ok())::(collect Vec<_>);
+
+        assert_eq!(res, vec![1, 2]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_flat_map() {
+    sonic_spin! {
+        let alt = (1..3).flat_map(|x| vec![x; x]).collect::<Vec<_>>();
+
+        let res = (1..3)::(flat_map |x| vec![x; x])::(collect Vec<_>);
+
+        assert_eq!(res, vec![1, 2, 2]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_position() {
+    sonic_spin! {
+        let alt = (1..5).position(|x| x > 2);
+
+        let res = (1..5)::(position |x| x > 2);
+
+        assert_eq!(res, Some(2));
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_find() {
+    sonic_spin! {
+        let alt = (1..5).find(|x| *x > 2);
+
+        let res = (1..5)::(find |x| *x > 2);
+
+        assert_eq!(res, Some(3));
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_any() {
+    sonic_spin! {
+        let alt = (1..5).any(|x| x > 3);
+
+        let res = (1..5)::(any |x| x > 3);
+
+        assert_eq!(res, true);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_all() {
+    sonic_spin! {
+        let alt = (1..5).all(|x| x > 3);
+
+        let res = (1..5)::(all |x| x > 3);
+
+        assert_eq!(res, false);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_scan() {
+    sonic_spin! {
+        let alt = (1..5)
+            .scan(0, |st, x| {
+                *st += x;
+                Some(*st)
+            })
+            .collect::<Vec<_>>();
+
+        let res = (1..5)::(scan 0 |st, x| {
+            *st += x;
+            Some(*st)
+        })::(collect Vec<_>);
+
+        assert_eq!(res, vec![1, 3, 6, 10]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_step_by() {
+    sonic_spin! {
+        let alt = (0..20).step_by(5).collect::<Vec<_>>();
+
+        let res = (0..20)::(step_by 5)::(collect Vec<_>);
+
+        assert_eq!(res, vec![0, 5, 10, 15]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_cycle_take() {
+    sonic_spin! {
+        let alt = (0..3).cycle().take(7).collect::<Vec<_>>();
+
+        let res = (0..3)::(cycle)::(take 7)::(collect Vec<_>);
+
+        assert_eq!(res, vec![0, 1, 2, 0, 1, 2, 0]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_peekable_fuse() {
+    sonic_spin! {
+        let mut alt = (0..3).fuse().peekable();
+        let alt = (alt.peek().cloned(), alt.count());
+
+        let mut res = (0..3)::(fuse)::(peekable);
+        let res = (res.peek().cloned(), res.count());
+
+        assert_eq!(res, (Some(0), 3));
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_reduce() {
+    sonic_spin! {
+        let alt = (1..=4).reduce(|a, b| a + b);
+
+        let res = (1..=4)::(reduce |a, b| a + b);
+
+        assert_eq!(res, Some(10));
+        assert_eq!(res, alt);
+
+        let res = (1..1)::(reduce |a, b| a + b);
+
+        assert_eq!(res, None);
+    }
+}
+
+#[test]
+fn iter_map_while() {
+    sonic_spin! {
+        let alt = (1..10)
+            .map_while(|x| if x < 4 { Some(x * 2) } else { None })
+            .collect::<Vec<_>>();
+
+        let res = (1..10)::(map_while |x| if x < 4 { Some(x * 2) } else { None })::(collect Vec<_>);
+
+        assert_eq!(res, vec![2, 4, 6]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_sum_boxed() {
+    sonic_spin! {
+        let alt = box (0..3).sum::<i32>();
+
+        let res = (0..3)::(sum i32)::(box);
+
+        assert_eq!(res, box 3);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_nth_last() {
+    sonic_spin! {
+        let alt = (1..5).nth(2);
+
+        let res = (1..5)::(nth 2);
+
+        assert_eq!(res, Some(3));
+        assert_eq!(res, alt);
+
+        let res = (1..5)::(nth 9);
+
+        assert_eq!(res, None);
+
+        let alt = (1..5).map(|x| x * 2).last();
+
+        let res = (1..5)::(map |x| x * 2)::(last);
+
+        assert_eq!(res, Some(8));
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn iter_map_keys_values() {
+    sonic_spin! {
+        let pairs = vec![(1, "a"), (2, "b")];
+        let alt: Vec<_> = pairs.iter().map(|&(k, v)| (k * 2, v)).collect();
+
+        let res: Vec<_> = pairs.iter().cloned()::(map_keys |k| k * 2)::(collect);
+
+        assert_eq!(res, vec![(2, "a"), (4, "b")]);
+        assert_eq!(res, alt);
+
+        let alt: Vec<_> = pairs.iter().map(|&(k, v)| (k, v.len())).collect();
+
+        let res: Vec<_> = pairs.iter().cloned()::(map_values |v: &str| v.len())::(collect);
+
+        assert_eq!(res, vec![(1, 1), (2, 1)]);
+        assert_eq!(res, alt);
+    }
+}
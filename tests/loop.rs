@@ -57,7 +57,40 @@ fn loop_nested_label() {
     assert_eq!(acc, _acc);
 }
 
-#[ignore]
+#[test]
+fn loop_continue() {
+    sonic_spin! {
+        let mut _acc = 0;
+        let mut _odd = 0;
+        loop {
+            _acc += 1;
+            if _acc > 6 {
+                break ();
+            };
+            if _acc % 2 == 0 {
+                continue;
+            };
+            _odd += 1;
+        };
+
+        let mut acc = 0;
+        let mut odd = 0;
+        {
+            acc += 1;
+            (acc > 6)::(if) {
+                ()::(break);
+            };
+            (acc % 2 == 0)::(if) {
+                ()::(continue);
+            };
+            odd += 1;
+        }::(loop);
+
+        assert_eq!((acc, odd), (7, 3));
+        assert_eq!((acc, odd), (_acc, _odd));
+    }
+}
+
 #[test]
 fn loop_insert_braces() {
     sonic_spin! {
@@ -68,12 +101,48 @@ fn loop_insert_braces() {
             }
         };
 
-        // TODO: automatically insert the surrouding braces
-        { // TODO: remove line
-            do_break::(if) {
-                break
+        do_break::(if) {
+            break
+        }::(loop);
+    }
+}
+
+#[test]
+fn loop_continue_labeled() {
+    sonic_spin! {
+        let mut _acc = 0;
+        'outer_: for x in 0..3 {
+            for y in 0..3 {
+                if y > x {
+                    continue 'outer_;
+                }
+                _acc += 1;
+            }
+        };
+
+        let mut acc = 0;
+        (0..3)::('outer: for x in) {
+            (0..3)::(for y in) {
+                (y > x)::(if) {
+                    ()::('outer: continue);
+                };
+                acc += 1;
             }
-        } // TODO: remove line
-        ::(loop);
+        };
+
+        assert_eq!(acc, 6);
+        assert_eq!(acc, _acc);
+
+        let mut acc = 0;
+        (0..3)::('outer2: for x in) {
+            (0..3)::(for y in) {
+                (y > x)::(if) {
+                    ()::(continue 'outer2);
+                };
+                acc += 1;
+            }
+        };
+
+        assert_eq!(acc, _acc);
     }
 }
@@ -0,0 +1,44 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn macro_normal() {
+    sonic_spin! {
+        let alt = dbg!(5);
+
+        let res = 5::(dbg!);
+
+        assert_eq!(res, 5);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn macro_path() {
+    sonic_spin! {
+        let alt = std::dbg!(5);
+
+        let res = 5::(std::dbg!);
+
+        assert_eq!(res, 5);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn macro_args() {
+    sonic_spin! {
+        let alt = format!("{}-{}", 1, 2);
+
+        let res = "{}-{}"::(format!(1, 2));
+
+        assert_eq!(res, "1-2");
+        assert_eq!(res, alt);
+
+        (1 + 1)::(assert_eq!(2));
+    }
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "sugars")]
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+use std::collections::HashMap;
+
+#[test]
+fn map_insert() {
+    sonic_spin! {
+        let mut alt_map = HashMap::new();
+        alt_map.insert("a", 1);
+        let alt = alt_map.insert("a", 2);
+
+        let mut map = HashMap::new();
+        map::(insert "a" 1);
+        let res = map::(insert "a" 2);
+
+        assert_eq!(res, Some(1));
+        assert_eq!(res, alt);
+        assert_eq!(map, alt_map);
+    }
+}
+
+#[test]
+fn map_remove() {
+    sonic_spin! {
+        let mut alt_map = HashMap::new();
+        alt_map.insert("a", 1);
+        let alt = alt_map.remove("a");
+
+        let mut map = HashMap::new();
+        map::(insert "a" 1);
+        let res = map::(remove "a");
+
+        assert_eq!(res, Some(1));
+        assert_eq!(res, alt);
+
+        let res = map::(remove "a");
+
+        assert_eq!(res, None);
+    }
+}
+
+#[test]
+fn map_entry_or_insert() {
+    sonic_spin! {
+        let mut alt = HashMap::new();
+        "abca".chars().for_each(|c| *alt.entry(c).or_insert(0) += 1);
+
+        let mut res = HashMap::new();
+        "abca".chars()::(for c in) {
+            *res.entry(c)::(or_insert 0) += 1;
+        };
+
+        assert_eq!(res[&'a'], 2);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn map_entry_or_insert_with_default() {
+    sonic_spin! {
+        let mut alt: HashMap<&str, Vec<u32>> = HashMap::new();
+        alt.entry("a").or_insert_with(Vec::new).push(1);
+        alt.entry("a").or_default().push(2);
+
+        let mut res: HashMap<&str, Vec<u32>> = HashMap::new();
+        res.entry("a")::(or_insert_with Vec::new).push(1);
+        res.entry("a")::(or_default).push(2);
+
+        assert_eq!(res["a"], vec![1, 2]);
+        assert_eq!(res, alt);
+    }
+}
@@ -1,5 +1,6 @@
 #![feature(proc_macro_hygiene)]
 #![allow(unused_parens)]
+#![feature(box_syntax)]
 
 mod common;
 use sonic_spin::sonic_spin;
@@ -46,3 +47,115 @@ fn match_nested() {
         assert_eq!(res, alt);
     }
 }
+
+#[test]
+fn match_single_block_arm() {
+    sonic_spin! {
+        let mut alt = 0;
+        let () = match 1 {
+            _ => {
+                alt += 1;
+            }
+        };
+
+        let mut res = 0;
+        let () = 1::(match) {
+            _ => {
+                res += 1;
+            }
+        };
+
+        assert_eq!(res, 1);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn match_guard_and_body() {
+    sonic_spin! {
+        let n = 3;
+
+        let alt = match n {
+            x if box x == box 3 => box x,
+            _ => box 0,
+        };
+
+        let res = n::(match) {
+            x if x::(box) == box 3 => x::(box),
+            _ => box 0,
+        };
+
+        assert_eq!(res, box 3);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn match_try_receiver() {
+    use std::num::ParseIntError;
+
+    sonic_spin! {
+        let alt = |s: &str| -> Result<u32, ParseIntError> {
+            match s.parse::<u32>()? {
+                0 => Ok(1),
+                x => Ok(x * 2),
+            }
+        };
+
+        let res = |s: &str| -> Result<u32, ParseIntError> {
+            s.parse::<u32>()?::(match) {
+                0 => Ok(1),
+                x => Ok(x * 2),
+            }
+        };
+
+        assert_eq!(res("0"), Ok(1));
+        assert_eq!(res("3"), Ok(6));
+        assert!(res("a").is_err());
+        assert_eq!(res("3"), alt("3"));
+    }
+}
+
+#[test]
+fn match_classic_arms() {
+    sonic_spin! {
+        let x = 0;
+
+        let alt = match x {
+            0 => box 1,
+            _ => box 2,
+        };
+
+        let res = match x {
+            0 => 1::(box),
+            _ => 2::(box),
+        };
+
+        assert_eq!(res, box 1);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn match_nested_classic() {
+    sonic_spin! {
+        let (x, y, z) = (0, 1, 2);
+
+        let alt = match x {
+            0 => match y {
+                _ => box z,
+            },
+            _ => box 0,
+        };
+
+        let res = x::(match) {
+            0 => match y {
+                _ => z::(box),
+            },
+            _ => box 0,
+        };
+
+        assert_eq!(res, box 2);
+        assert_eq!(res, alt);
+    }
+}
@@ -0,0 +1,44 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn method_normal() {
+    sonic_spin! {
+        let data = vec![1, 2, 3];
+        let alt = data.len();
+
+        let res = data::(.len());
+
+        assert_eq!(res, 3);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn method_reference() {
+    sonic_spin! {
+        let data = vec![1, 2, 3];
+        let alt = (&data).len();
+
+        let res = data::(&)::(.len());
+
+        assert_eq!(res, 3);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn method_range_turbofish() {
+    sonic_spin! {
+        let alt = (0..10).sum::<i32>();
+
+        let res = (0..10)::(.sum::<i32>());
+
+        assert_eq!(res, 45);
+        assert_eq!(res, alt);
+    }
+}
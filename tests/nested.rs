@@ -0,0 +1,36 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+#![feature(box_syntax)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+// The outer `sonic_spin!` keeps macro arguments as they are, so the inner
+// invocation is only expanded once, by itself.
+
+#[test]
+fn nested_expr() {
+    sonic_spin! {
+        let alt = box box 1;
+
+        let res = sonic_spin!(1::(box))::(box);
+
+        assert_eq!(res, box box 1);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn nested_macro_arg() {
+    sonic_spin! {
+        let x = 2;
+        let alt = -x;
+
+        assert_eq!(sonic_spin!(x::(-)), alt);
+
+        let res = sonic_spin! { x::(-) }::(-);
+
+        assert_eq!(res, x);
+    }
+}
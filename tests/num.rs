@@ -0,0 +1,96 @@
+#![cfg(feature = "sugars")]
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+#![feature(box_syntax)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn num_abs() {
+    sonic_spin! {
+        let alt = (-5i32).abs();
+
+        let res = (-5i32)::(abs);
+
+        assert_eq!(res, 5);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn num_pow() {
+    sonic_spin! {
+        let alt = 2i32.pow(10);
+
+        let res = 2i32::(pow 10);
+
+        assert_eq!(res, 1024);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn num_sqrt() {
+    sonic_spin! {
+        let alt = 4f64.sqrt();
+
+        let res = 4f64::(sqrt);
+
+        assert_eq!(res, 2.0);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn num_wrapping_add() {
+    sonic_spin! {
+        let alt = std::u8::MAX.wrapping_add(1);
+
+        let res = std::u8::MAX::(wrapping_add 1);
+
+        assert_eq!(res, 0);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn num_checked_add() {
+    sonic_spin! {
+        let alt = 255u8.checked_add(1);
+
+        let res = 255u8::(checked_add 1);
+
+        assert_eq!(res, None);
+        assert_eq!(res, alt);
+
+        let res = 254u8::(checked_add 1);
+
+        assert_eq!(res, Some(255));
+    }
+}
+
+#[test]
+fn num_saturating_add() {
+    sonic_spin! {
+        let alt = 250u8.saturating_add(10);
+
+        let res = 250u8::(saturating_add 10);
+
+        assert_eq!(res, 255);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn num_abs_boxed_negative() {
+    sonic_spin! {
+        let alt = (box (-5i32)).abs();
+
+        let res = (-5i32)::(box)::(abs);
+
+        assert_eq!(res, 5);
+        assert_eq!(res, alt);
+    }
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "sugars")]
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn option_as_deref() {
+    sonic_spin! {
+        let opt = Some("hi".to_string());
+        let alt: Option<&str> = opt.as_deref();
+
+        let res: Option<&str> = opt::(as_deref);
+
+        assert_eq!(res, Some("hi"));
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn option_cloned() {
+    sonic_spin! {
+        let x = 3;
+        let alt = Some(&x).cloned();
+
+        let res = Some(&x)::(cloned);
+
+        assert_eq!(res, Some(3));
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn option_map_or() {
+    sonic_spin! {
+        let alt = Some(3).map_or(0, |x| x * 2);
+
+        let res = Some(3)::(map_or 0 |x| x * 2);
+
+        assert_eq!(res, 6);
+        assert_eq!(res, alt);
+
+        let none: Option<i32> = None;
+        let alt = none.map_or(0, |x| x * 2);
+
+        let res = none::(map_or 0 |x| x * 2);
+
+        assert_eq!(res, 0);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn option_map_or_else() {
+    sonic_spin! {
+        let none: Option<i32> = None;
+        let alt = none.map_or_else(|| -1, |x| x * 2);
+
+        let res = none::(map_or_else (|| -1) |x| x * 2);
+
+        assert_eq!(res, -1);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn option_map_or_else_closures() {
+    sonic_spin! {
+        let some = Some(3);
+        let alt = some.map_or_else(|| -1, |x| x * 2);
+
+        let res = some::(map_or_else || -1 |x| x * 2);
+
+        assert_eq!(res, 6);
+        assert_eq!(res, alt);
+    }
+}
@@ -0,0 +1,40 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn paren_normal() {
+    sonic_spin! {
+        let (a, b) = (1, 2);
+        let alt = a + (b);
+
+        let res = a + b::(paren);
+
+        assert_eq!(res, 3);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn paren_cast() {
+    sonic_spin! {
+        let x = 300i32;
+        let alt = (x as u8).count_ones();
+
+        let res = x::(as u8)::(paren)::(.count_ones());
+
+        assert_eq!(res, 3);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn paren_tokens() {
+    let res = sonic_spin_core::spin("a + b::(paren)::(as u8)".parse().unwrap()).unwrap();
+    let alt: proc_macro2::TokenStream = "{ a + (b) as u8 }".parse().unwrap();
+
+    assert_eq!(res.to_string(), alt.to_string());
+}
@@ -0,0 +1,145 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+#![feature(box_syntax)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+// Guards that a turboball is accepted wherever an expression is expected.
+// Macro arguments are the exception, as they are kept as they are written.
+
+#[test]
+fn position_statement() {
+    sonic_spin! {
+        let mut acc = 0;
+        (acc += 1)::(box);
+
+        assert_eq!(acc, 1);
+    }
+}
+
+#[test]
+fn position_let_init() {
+    sonic_spin! {
+        let x = &2;
+        let res = x::(*);
+
+        assert_eq!(res, 2);
+    }
+}
+
+#[test]
+fn position_call_arg() {
+    sonic_spin! {
+        fn id(x: i32) -> i32 {
+            x
+        }
+        let x = &2;
+        let res = id(x::(*));
+
+        assert_eq!(res, 2);
+    }
+}
+
+#[test]
+fn position_method_arg() {
+    sonic_spin! {
+        let x = &2;
+        let res = 1i32.max(x::(*));
+
+        assert_eq!(res, 2);
+    }
+}
+
+#[test]
+fn position_index() {
+    sonic_spin! {
+        let v = [10, 20, 30];
+        let i = &1;
+        let res = v[i::(*)];
+
+        assert_eq!(res, 20);
+    }
+}
+
+#[test]
+fn position_repeat_len() {
+    sonic_spin! {
+        const LEN: usize = 3;
+        let res = [0; LEN::(&)::(*)];
+
+        assert_eq!(res.len(), 3);
+    }
+}
+
+#[test]
+fn position_match_scrutinee() {
+    sonic_spin! {
+        let x = &2;
+        let res = match x::(*) {
+            2 => true,
+            _ => false,
+        };
+
+        assert!(res);
+    }
+}
+
+#[test]
+fn position_array_element() {
+    sonic_spin! {
+        let x = &2;
+        let res = [1, x::(*), 3];
+
+        assert_eq!(res, [1, 2, 3]);
+    }
+}
+
+#[test]
+fn position_tuple_element() {
+    sonic_spin! {
+        let x = &2;
+        let res = (1, x::(*));
+
+        assert_eq!(res, (1, 2));
+    }
+}
+
+#[test]
+fn position_struct_field() {
+    sonic_spin! {
+        #[derive(Debug, PartialEq)]
+        struct Point {
+            x: i32,
+            y: Box<i32>,
+        }
+        let x = &2;
+        let res = Point {
+            x: x::(*),
+            y: 3::(box),
+        };
+
+        assert_eq!(res, Point { x: 2, y: box 3 });
+    }
+}
+
+#[test]
+fn position_condition() {
+    sonic_spin! {
+        let x = false;
+        let res = if x::(!) { 1 } else { 2 };
+
+        assert_eq!(res, 1);
+    }
+}
+
+#[test]
+fn position_closure_body() {
+    sonic_spin! {
+        let f = |x: &i32| x::(*);
+        let res = f(&2);
+
+        assert_eq!(res, 2);
+    }
+}
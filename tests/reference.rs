@@ -28,3 +28,16 @@ fn reference_multiple() {
         assert_eq!(alt, res);
     }
 }
+
+#[test]
+fn reference_comparison() {
+    sonic_spin! {
+        let x = 5;
+        let alt = (&x) == &5;
+
+        let res = x::(&) == &5;
+
+        assert_eq!(res, true);
+        assert_eq!(res, alt);
+    }
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "sugars")]
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn result_unwrap_err() {
+    sonic_spin! {
+        let x: Result<u32, &str> = Err("spin");
+        let alt = x.unwrap_err();
+
+        let res = x::(unwrap_err);
+
+        assert_eq!(res, "spin");
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+#[should_panic]
+fn result_unwrap_err_ok() {
+    sonic_spin! {
+        let x: Result<u32, &str> = Ok(3);
+        x::(unwrap_err);
+    }
+}
@@ -1,5 +1,6 @@
 #![feature(proc_macro_hygiene)]
 #![allow(unused_parens)]
+#![feature(box_syntax)]
 
 mod common;
 
@@ -24,3 +25,45 @@ fn return_normal() {
         assert_eq!(res, alt);
     }
 }
+
+#[test]
+fn return_in_if() {
+    sonic_spin! {
+        let alt = |cond: bool| -> u32 {
+            if cond {
+                return 5;
+            };
+            7
+        };
+
+        let res = |cond: bool| -> u32 {
+            cond::(if) {
+                5::(return);
+            };
+            7
+        };
+
+        assert_eq!(res(true), 5);
+        assert_eq!(res(false), 7);
+        assert_eq!(res(true), alt(true));
+        assert_eq!(res(false), alt(false));
+    }
+}
+
+#[test]
+fn return_call_argument() {
+    sonic_spin! {
+        let double = |x: Box<u32>| box (*x * 2);
+
+        let alt = |x: u32| -> Box<u32> {
+            return double(box x);
+        };
+
+        let res = |x: u32| -> Box<u32> {
+            double(x::(box))::(return);
+        };
+
+        assert_eq!(res(3), box 6);
+        assert_eq!(res(3), alt(3));
+    }
+}
@@ -0,0 +1,39 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+#![feature(box_syntax)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn single_expr_box() {
+    let alt = box 2;
+
+    let res = sonic_spin!(2::(box));
+
+    assert_eq!(res, box 2);
+    assert_eq!(res, alt);
+}
+
+#[test]
+fn single_expr_trailing_operators() {
+    let x = 3;
+    let alt = -x * 2 + 1;
+
+    let res = sonic_spin!(x::(-) * 2 + 1);
+
+    assert_eq!(res, -5);
+    assert_eq!(res, alt);
+}
+
+#[test]
+fn single_expr_chain() {
+    let x = 3;
+    let alt = box *&x;
+
+    let res = sonic_spin!(x::(&)::(*)::(box));
+
+    assert_eq!(res, box 3);
+    assert_eq!(res, alt);
+}
@@ -0,0 +1,194 @@
+#![cfg(feature = "sugars")]
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn slice_to_vec() {
+    sonic_spin! {
+        let slice: &[u32] = &[1, 2, 3];
+        let alt = slice.to_vec();
+
+        let res = slice::(to_vec);
+
+        assert_eq!(res, vec![1, 2, 3]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn slice_get() {
+    sonic_spin! {
+        let v = vec![1, 2, 3];
+        let alt = v.get(0);
+
+        let res = v::(get 0);
+
+        assert_eq!(res, Some(&1));
+        assert_eq!(res, alt);
+
+        let alt = v.get(5);
+
+        let res = v::(get 5);
+
+        assert_eq!(res, None);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn slice_get_mut() {
+    sonic_spin! {
+        let mut v = vec![1, 2, 3];
+        let mut w = vec![1, 2, 3];
+        let alt = w.get_mut(0);
+
+        let res = v::(get_mut 0);
+
+        assert_eq!(res, Some(&mut 1));
+        assert_eq!(res, alt);
+
+        let res = v::(get_mut 5);
+
+        assert_eq!(res, None);
+    }
+}
+
+#[test]
+fn slice_contains() {
+    sonic_spin! {
+        let v = vec![1, 2, 3];
+        let alt = v.contains(&3);
+
+        let res = v::(contains &3);
+
+        assert_eq!(res, true);
+        assert_eq!(res, alt);
+
+        let res = v::(contains &4);
+
+        assert_eq!(res, false);
+    }
+}
+
+#[test]
+fn slice_join() {
+    sonic_spin! {
+        let alt = vec!["a", "b", "c"].join("-");
+
+        let res = vec!["a", "b", "c"]::(join "-");
+
+        assert_eq!(res, "a-b-c");
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn slice_windows() {
+    sonic_spin! {
+        let alt = [1, 2, 3, 4].windows(2).map(|w| w.to_vec()).collect::<Vec<_>>();
+
+        let res = [1, 2, 3, 4]::(windows 2)::(map |w| w.to_vec())::(collect Vec<_>);
+
+        assert_eq!(res, vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn slice_chunks() {
+    sonic_spin! {
+        let alt = [1, 2, 3, 4].chunks(3).map(|c| c.to_vec()).collect::<Vec<_>>();
+
+        let res = [1, 2, 3, 4]::(chunks 3)::(map |c| c.to_vec())::(collect Vec<_>);
+
+        assert_eq!(res, vec![vec![1, 2, 3], vec![4]]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn slice_as_ptr() {
+    sonic_spin! {
+        let mut v = vec![1, 2, 3];
+        let alt = unsafe { *v.as_ptr().add(1) };
+
+        let res = unsafe { *v::(as_ptr).add(1) };
+
+        assert_eq!(res, 2);
+        assert_eq!(res, alt);
+
+        let ptr = v::(as_mut_ptr);
+        unsafe { *ptr = 7 };
+
+        assert_eq!(v, vec![7, 2, 3]);
+    }
+}
+
+#[test]
+fn slice_split_at() {
+    sonic_spin! {
+        let slice: &[u32] = &[1, 2, 3];
+        let (alt_left, alt_right) = slice.split_at(2);
+
+        slice::(split_at 2)::(let (left, right) =);
+
+        assert_eq!((left, right), (&[1, 2][..], &[3][..]));
+        assert_eq!((left, right), (alt_left, alt_right));
+    }
+}
+
+#[test]
+fn slice_split_first_last() {
+    sonic_spin! {
+        let slice: &[u32] = &[1, 2, 3];
+        let alt = slice.split_first();
+
+        let res = slice::(split_first);
+
+        assert_eq!(res, Some((&1, &[2, 3][..])));
+        assert_eq!(res, alt);
+
+        let first = slice::(split_first)::(match) {
+            Some((first, _rest)) => *first,
+            None => 0,
+        };
+
+        assert_eq!(first, 1);
+
+        let alt = slice.split_last();
+
+        let res = slice::(split_last);
+
+        assert_eq!(res, Some((&3, &[1, 2][..])));
+        assert_eq!(res, alt);
+
+        let empty: &[u32] = &[];
+
+        let res = empty::(split_last);
+
+        assert_eq!(res, None);
+    }
+}
+
+#[test]
+fn slice_first_last() {
+    sonic_spin! {
+        let slice: &[u32] = &[1, 2, 3];
+        let alt = (slice.first(), slice.last());
+
+        let res = (slice::(first), slice::(last));
+
+        assert_eq!(res, (Some(&1), Some(&3)));
+        assert_eq!(res, alt);
+
+        let empty: &[u32] = &[];
+
+        let res = (empty::(first), empty::(last));
+
+        assert_eq!(res, (None, None));
+    }
+}
@@ -0,0 +1,33 @@
+#![cfg(not(feature = "sugars"))]
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+// Without the `sugars` feature, only the operator marks and calls are
+// accepted; see the `sonic_spin` docs for the rejected sugar case.
+
+#[test]
+fn strict_operator() {
+    sonic_spin! {
+        let x = 2;
+        let alt = -x;
+
+        let res = x::(-);
+
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn strict_call() {
+    sonic_spin! {
+        let alt = Some(2);
+
+        let res = 2::(Some);
+
+        assert_eq!(res, alt);
+    }
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "sugars")]
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn string_parse() {
+    sonic_spin! {
+        let alt: Result<u32, _> = "42".to_string().parse();
+
+        let res: Result<u32, _> = "42".to_string()::(parse);
+
+        assert_eq!(res, Ok(42));
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn string_parse_turbofish() {
+    sonic_spin! {
+        let alt = "42".to_string().parse::<u32>();
+
+        let res = "42".to_string()::(parse u32);
+
+        assert_eq!(res, Ok(42));
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn string_trim_split() {
+    sonic_spin! {
+        let s = " a,b,c ".to_string();
+        let alt = s.trim().split(',').collect::<Vec<_>>();
+
+        let res = s::(trim)::(split ',')::(collect Vec<_>);
+
+        assert_eq!(res, vec!["a", "b", "c"]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn string_lines() {
+    sonic_spin! {
+        let s = "a\nb".to_string();
+        let alt = s.lines().collect::<Vec<_>>();
+
+        let res = s::(lines)::(collect Vec<_>);
+
+        assert_eq!(res, vec!["a", "b"]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn string_starts_ends_with() {
+    sonic_spin! {
+        let s = "prefix";
+        let alt = (s.starts_with("pre"), s.ends_with("fix"));
+
+        let res = (s::(starts_with "pre"), s::(ends_with "fix"));
+
+        assert_eq!(res, (true, true));
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn string_case() {
+    sonic_spin! {
+        let alt = "Hi".to_string().to_uppercase();
+
+        let res = "Hi".to_string()::(to_uppercase);
+
+        assert_eq!(res, "HI");
+        assert_eq!(res, alt);
+
+        let res = res::(to_lowercase);
+
+        assert_eq!(res, "hi");
+    }
+}
+
+#[test]
+fn string_chars_bytes() {
+    sonic_spin! {
+        let alt = "héllo".to_string().chars().count();
+
+        let res = "héllo".to_string()::(chars)::(count);
+
+        assert_eq!(res, 5);
+        assert_eq!(res, alt);
+
+        let res = "héllo".to_string()::(bytes)::(count);
+
+        assert_eq!(res, 6);
+    }
+}
@@ -15,3 +15,46 @@ fn try_normal() {
         assert_eq!(res, alt);
     }
 }
+
+#[test]
+fn try_question() {
+    use std::num::ParseIntError;
+
+    sonic_spin! {
+        let alt = |s: &str| -> Result<usize, ParseIntError> {
+            Ok(s.parse::<u32>()? as usize)
+        };
+
+        let res = |s: &str| -> Result<usize, ParseIntError> {
+            Ok(s.parse::<u32>()::(?)::(as usize))
+        };
+
+        assert_eq!(res("8"), Ok(8));
+        assert!(res("a").is_err());
+        assert_eq!(res("8"), alt("8"));
+    }
+}
+
+#[test]
+fn try_let() {
+    use std::collections::HashMap;
+
+    sonic_spin! {
+        let mut config = HashMap::new();
+        config.insert("key", 8);
+
+        let alt = |key: &str| -> Result<u32, &'static str> {
+            let value = config.get(key).ok_or("missing")?;
+            Ok(value + 1)
+        };
+
+        let res = |key: &str| -> Result<u32, &'static str> {
+            config.get(key).ok_or("missing")?::(let value =);
+            Ok(value + 1)
+        };
+
+        assert_eq!(res("key"), Ok(9));
+        assert_eq!(res("other"), Err("missing"));
+        assert_eq!(res("key"), alt("key"));
+    }
+}
@@ -0,0 +1,12 @@
+// The expected errors are the `.stderr` files next to each case, which are
+// regenerated by running `TRYBUILD=overwrite cargo test --test ui`.
+//
+// The `trace-expansion` feature prints every expansion to stderr, so the
+// suite is skipped under it.
+#![cfg(not(feature = "trace-expansion"))]
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
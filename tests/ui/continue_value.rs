@@ -0,0 +1,10 @@
+#![feature(proc_macro_hygiene)]
+use sonic_spin::sonic_spin;
+
+fn main() {
+    sonic_spin! {
+        loop {
+            1::(continue);
+        }
+    }
+}
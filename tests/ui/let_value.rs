@@ -0,0 +1,8 @@
+#![feature(proc_macro_hygiene)]
+use sonic_spin::sonic_spin;
+
+fn main() {
+    sonic_spin! {
+        let _ = 1::(let x =);
+    }
+}
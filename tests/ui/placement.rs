@@ -0,0 +1,9 @@
+#![feature(proc_macro_hygiene)]
+use sonic_spin::sonic_spin;
+
+fn main() {
+    sonic_spin! {
+        let mut x = 0;
+        x::(<- 1);
+    }
+}
@@ -55,3 +55,24 @@ fn un_minus_deref() {
         assert_eq!(res, alt);
     }
 }
+
+#[test]
+fn un_precedence() {
+    sonic_spin! {
+        fn square(x: i32) -> i32 {
+            x * x
+        }
+        let x = 2;
+        let alt = -(square(x));
+
+        // the turboball binds to `x` before the prefix `-` applies
+        let res = -x::(square);
+
+        assert_eq!(res, -4);
+        assert_eq!(res, alt);
+
+        let res = (-x)::(square);
+
+        assert_eq!(res, 4);
+    }
+}
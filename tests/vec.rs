@@ -0,0 +1,82 @@
+#![cfg(feature = "sugars")]
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+
+#[test]
+fn vec_dedup() {
+    sonic_spin! {
+        let mut alt = vec![1, 1, 2, 2, 3];
+        alt.dedup();
+
+        let res = vec![1, 1, 2, 2, 3]::(dedup);
+
+        assert_eq!(res, vec![1, 2, 3]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn vec_retain() {
+    sonic_spin! {
+        let mut alt = vec![-1, 2, -3, 4];
+        alt.retain(|x| *x > 0);
+
+        let v = vec![-1, 2, -3, 4];
+        let res = v::(retain |x| *x > 0);
+
+        assert_eq!(res, vec![2, 4]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn vec_dedup_by_key() {
+    sonic_spin! {
+        let mut alt = vec![1, 3, 4, 6, 7, 8];
+        alt.dedup_by_key(|x| *x % 2);
+
+        let v = vec![1, 3, 4, 6, 7, 8];
+        let res = v::(dedup_by_key |x| *x % 2);
+
+        assert_eq!(res, vec![1, 4, 7, 8]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn vec_rotate() {
+    sonic_spin! {
+        let mut alt = vec![1, 2, 3, 4, 5];
+        alt.rotate_left(2);
+
+        let res = vec![1, 2, 3, 4, 5]::(rotate_left 2);
+
+        assert_eq!(res, vec![3, 4, 5, 1, 2]);
+        assert_eq!(res, alt);
+
+        alt.rotate_right(2);
+
+        let res = res::(rotate_right 2);
+
+        assert_eq!(res, vec![1, 2, 3, 4, 5]);
+        assert_eq!(res, alt);
+    }
+}
+
+#[test]
+fn vec_extend() {
+    sonic_spin! {
+        let mut alt = vec![7];
+        alt.extend(0..3);
+
+        let v = vec![7];
+        let res = v::(extend 0..3);
+
+        assert_eq!(res, vec![7, 0, 1, 2]);
+        assert_eq!(res, alt);
+    }
+}
@@ -91,3 +91,68 @@ fn while_nested_labeled() {
         assert_eq!(acc, _acc);
     }
 }
+
+#[test]
+fn while_let_labeled() {
+    sonic_spin! {
+        let mut _iter = 0..10;
+        let mut _acc = 0;
+        'outer_: while let Some(x) = _iter.next() {
+            loop {
+                if x == 3 {
+                    break 'outer_;
+                }
+                _acc += x;
+                break;
+            }
+        };
+
+        let mut iter = 0..10;
+        let mut acc = 0;
+        iter.next()::('outer: while let Some(x) =) {
+            loop {
+                (x == 3)::(if) {
+                    break 'outer;
+                };
+                acc += x;
+                break;
+            }
+        };
+
+        assert_eq!(acc, 3);
+        assert_eq!(acc, _acc);
+    }
+}
+
+#[test]
+fn while_let_mark() {
+    use std::sync::mpsc;
+
+    sonic_spin! {
+        let (tx, rx) = mpsc::channel();
+        (1..4).for_each(|x| tx.send(x).unwrap());
+        drop(tx);
+
+        let mut res = 0;
+        rx.recv()::(while let Ok(msg) =) {
+            res += msg;
+        };
+
+        assert_eq!(res, 6);
+
+        let mut stack = vec![1, 2, 3];
+        let mut alt = vec![];
+        while let Some(x) = stack.pop() {
+            alt.push(x);
+        };
+
+        let mut stack = vec![1, 2, 3];
+        let mut res = vec![];
+        stack.pop()::(while let Some(x) =) {
+            res.push(x);
+        };
+
+        assert_eq!(res, vec![3, 2, 1]);
+        assert_eq!(res, alt);
+    }
+}
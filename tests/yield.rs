@@ -0,0 +1,52 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_parens)]
+#![feature(box_syntax)]
+#![feature(generators, generator_trait)]
+
+mod common;
+
+use sonic_spin::sonic_spin;
+use std::ops::{Generator, GeneratorState};
+use std::pin::Pin;
+
+#[test]
+fn yield_normal() {
+    sonic_spin! {
+        let mut alt = || {
+            yield 3u32;
+        };
+
+        let mut res = || {
+            3u32::(yield);
+        };
+
+        match (Pin::new(&mut alt).resume(()), Pin::new(&mut res).resume(())) {
+            (GeneratorState::Yielded(alt), GeneratorState::Yielded(res)) => {
+                assert_eq!(res, 3);
+                assert_eq!(res, alt);
+            }
+            _ => panic!("expected both generators to yield"),
+        }
+    }
+}
+
+#[test]
+fn yield_box() {
+    sonic_spin! {
+        let mut alt = || {
+            yield box 3u32;
+        };
+
+        let mut res = || {
+            3u32::(box)::(yield);
+        };
+
+        match (Pin::new(&mut alt).resume(()), Pin::new(&mut res).resume(())) {
+            (GeneratorState::Yielded(alt), GeneratorState::Yielded(res)) => {
+                assert_eq!(res, box 3);
+                assert_eq!(res, alt);
+            }
+            _ => panic!("expected both generators to yield"),
+        }
+    }
+}